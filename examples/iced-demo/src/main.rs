@@ -10,6 +10,7 @@ use iced::{mouse, Color, Font, Point, Rectangle, Renderer, Task};
 use iced::{Element, Length, Theme};
 use lilt::Animated;
 use lilt::Easing;
+use lilt::Timeline;
 use std::f32::consts::PI;
 use std::time::Instant;
 
@@ -23,7 +24,7 @@ pub fn main() -> iced::Result {
 struct Example {
     spinner_trim: Animated<bool, Instant>,
     spinner_rotation: Animated<bool, Instant>,
-    bars: Vec<Animated<bool, Instant>>,
+    bars: Timeline<bool, Instant>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -40,27 +41,18 @@ impl Default for Example {
 impl Example {
     fn new() -> Self {
         let time = std::time::Instant::now();
-        let left: Vec<Animated<bool, Instant>> = (0..50)
-            .map(|i| {
+        // Bars ripple outwards from the center
+        let mut bars = (0..100).fold(Timeline::new(), |timeline, i: i32| {
+            let distance_from_center = if i < 50 { 49 - i } else { i - 50 };
+            timeline.at(
+                distance_from_center as f32 * 30.,
                 Animated::new(false)
                     .duration(800.)
                     .easing(Easing::EaseInOutBounce)
-                    .delay(i as f32 * 30.)
-                    .repeat_forever()
-                    .auto_start(true, time)
-            })
-            .rev()
-            .collect();
-        let right: Vec<Animated<bool, Instant>> = (0..50)
-            .map(|i| {
-                Animated::new(false)
-                    .duration(800.)
-                    .easing(Easing::EaseInOutBounce)
-                    .delay(i as f32 * 30.)
-                    .repeat_forever()
-                    .auto_start(true, time)
-            })
-            .collect();
+                    .repeat_forever(),
+            )
+        });
+        bars.transition(true, time);
         Self {
             spinner_trim: Animated::new(false)
                 .duration(900.)
@@ -72,7 +64,7 @@ impl Example {
                 .duration(900.)
                 .repeat_forever()
                 .auto_start(true, time),
-            bars: [left, right].concat(),
+            bars,
         }
     }
 
@@ -96,6 +88,7 @@ impl Example {
                 Row::new()
                     .extend(
                         self.bars
+                            .children()
                             .iter()
                             .map(|b| {
                                 Container::new(Space::new(
@@ -11,8 +11,8 @@ use iced::{
     mouse, Background, Border, Color, Font, Point, Rectangle, Renderer, Subscription, Task,
 };
 use iced::{Element, Length, Theme};
-use lilt::{Animated, FloatRepresentable};
-use lilt::{Easing, Interpolable};
+use lilt::{color, Easing};
+use lilt::{Animated, FloatRepresentable, Transition};
 use std::default::Default;
 use std::f32::consts::PI;
 use std::time::{Duration, Instant};
@@ -23,23 +23,13 @@ pub fn main() -> iced::Result {
         .run()
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FloatRepresentable)]
 enum IndicatorState {
     Analyzing,
     Safe,
     Warning,
 }
 
-impl FloatRepresentable for IndicatorState {
-    fn float_value(&self) -> f32 {
-        match self {
-            IndicatorState::Analyzing => 0.,
-            IndicatorState::Safe => 1.,
-            IndicatorState::Warning => 2.,
-        }
-    }
-}
-
 struct Example {
     spinner_rotation: Animated<bool, Instant>,
     spinner_rotation_speed: Animated<bool, Instant>,
@@ -81,7 +71,12 @@ impl Example {
                         Easing::EaseInOutElastic.value(l)
                     }
                 }))
-                .duration(200.),
+                .duration(200.)
+                .transition_rule(
+                    None,
+                    Some(IndicatorState::Analyzing),
+                    Transition::new().easing(Easing::EaseInOut).duration(400.),
+                ),
         }
     }
 
@@ -114,51 +109,42 @@ impl Example {
 
     fn view(&self) -> Element<AppMessage> {
         let time = std::time::Instant::now();
-        let backing_color_analyzing = Color::from_rgb8(187, 218, 252);
-        let fg_color_analyzing = Color::from_rgb8(96, 162, 241);
-        let capsule_color_analyzing = Color::from_rgb8(230, 242, 254);
+        let backing_color_analyzing = color::Color::from_rgb8(187, 218, 252);
+        let fg_color_analyzing = color::Color::from_rgb8(96, 162, 241);
+        let capsule_color_analyzing = color::Color::from_rgb8(230, 242, 254);
 
-        let backing_color_warning = Color::from_rgb8(187, 218, 252);
-        let fg_color_warning = Color::from_rgb8(235, 75, 67);
-        let capsule_color_warning = Color::from_rgb8(250, 226, 227);
+        let backing_color_warning = color::Color::from_rgb8(187, 218, 252);
+        let fg_color_warning = color::Color::from_rgb8(235, 75, 67);
+        let capsule_color_warning = color::Color::from_rgb8(250, 226, 227);
 
-        let backing_color_safe = Color::from_rgb8(187, 218, 252);
-        let fg_color_safe = Color::from_rgb8(96, 189, 93);
-        let capsule_color_safe = Color::from_rgb8(220, 242, 220);
+        let backing_color_safe = color::Color::from_rgb8(187, 218, 252);
+        let fg_color_safe = color::Color::from_rgb8(96, 189, 93);
+        let capsule_color_safe = color::Color::from_rgb8(220, 242, 220);
 
-        let backing_color = self
-            .indicator_state
-            .animate::<InterpolableColor>(
-                |a| match a {
-                    IndicatorState::Safe => backing_color_safe.into(),
-                    IndicatorState::Warning => backing_color_warning.into(),
-                    IndicatorState::Analyzing => backing_color_analyzing.into(),
-                },
-                time,
-            )
-            .color;
-        let fg_color = self
-            .indicator_state
-            .animate::<InterpolableColor>(
-                |a| match a {
-                    IndicatorState::Safe => fg_color_safe.into(),
-                    IndicatorState::Warning => fg_color_warning.into(),
-                    IndicatorState::Analyzing => fg_color_analyzing.into(),
-                },
-                time,
-            )
-            .color;
-        let capsule_color = self
-            .indicator_state
-            .animate::<InterpolableColor>(
-                |a| match a {
-                    IndicatorState::Safe => capsule_color_safe.into(),
-                    IndicatorState::Warning => capsule_color_warning.into(),
-                    IndicatorState::Analyzing => capsule_color_analyzing.into(),
-                },
-                time,
-            )
-            .color;
+        let backing_color = iced_color(self.indicator_state.animate(
+            |a| match a {
+                IndicatorState::Safe => backing_color_safe,
+                IndicatorState::Warning => backing_color_warning,
+                IndicatorState::Analyzing => backing_color_analyzing,
+            },
+            time,
+        ));
+        let fg_color = iced_color(self.indicator_state.animate(
+            |a| match a {
+                IndicatorState::Safe => fg_color_safe,
+                IndicatorState::Warning => fg_color_warning,
+                IndicatorState::Analyzing => fg_color_analyzing,
+            },
+            time,
+        ));
+        let capsule_color = iced_color(self.indicator_state.animate(
+            |a| match a {
+                IndicatorState::Safe => capsule_color_safe,
+                IndicatorState::Warning => capsule_color_warning,
+                IndicatorState::Analyzing => capsule_color_analyzing,
+            },
+            time,
+        ));
 
         let height = 150.;
         let font = Font {
@@ -369,27 +355,8 @@ impl Example {
     }
 }
 
-impl From<Color> for InterpolableColor {
-    fn from(value: Color) -> Self {
-        Self { color: value }
-    }
-}
-
-struct InterpolableColor {
-    color: Color,
-}
-
-impl Interpolable for InterpolableColor {
-    fn interpolated(&self, other: Self, ratio: f32) -> Self {
-        let a = self.color;
-        let b = other.color;
-        let mix = Color::from_rgb(
-            a.r + ((b.r - a.r) * ratio),
-            a.g + ((b.g - a.g) * ratio),
-            a.b + ((b.b - a.b) * ratio),
-        );
-        Self { color: mix }
-    }
+fn iced_color(color: color::Color) -> Color {
+    Color::from_rgba(color.r, color.g, color.b, color.a)
 }
 
 #[derive(Debug)]
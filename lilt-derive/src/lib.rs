@@ -0,0 +1,359 @@
+//! Derive macros for [lilt](https://docs.rs/lilt), enabled with lilt's `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::ParseStream;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Lit, Token, Type};
+
+/// Derives `lilt::FloatRepresentable` for enums without fields
+///
+/// Each variant is represented by its discriminant, or by a value given with
+/// `#[lilt(value = ...)]`. Since these values determine the direction of a transition
+/// they also determine when asymmetric settings are used.
+///
+/// ```rust,ignore
+/// #[derive(FloatRepresentable, Clone, Copy, PartialEq)]
+/// enum State {
+///     Idle,
+///     Loading,
+///     #[lilt(value = -1.0)]
+///     Failed,
+/// }
+/// ```
+#[proc_macro_derive(FloatRepresentable, attributes(lilt))]
+pub fn derive_float_representable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_representable(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `lilt::Interpolable` for structs & enums by interpolating every field
+///
+/// Fields can be configured using the `lilt` attribute:
+/// - `#[lilt(skip)]` doesn't interpolate the field, the destination value is used immediately
+/// - `#[lilt(snap = 0.5)]` switches from the origin to the destination value once the ratio
+///   reaches the threshold. Snapped fields must implement `Clone`.
+///
+/// Enums interpolate fields when both values are the same variant. Otherwise the value snaps
+/// to the destination halfway, which can be configured with `#[lilt(snap = ...)]` on the enum.
+/// Enums must implement `Clone`.
+///
+/// ```rust,ignore
+/// #[derive(Interpolable)]
+/// struct Style {
+///     opacity: f32,
+///     offset: (f32, f32),
+///     #[lilt(snap = 0.5)]
+///     label: String,
+///     #[lilt(skip)]
+///     id: u64,
+/// }
+/// ```
+#[proc_macro_derive(Interpolable, attributes(lilt))]
+pub fn derive_interpolable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_interpolable(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn variant_value(attrs: &[Attribute]) -> syn::Result<Option<f32>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("lilt")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("value") {
+                value = Some(parse_f32(meta.value()?)?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `value = ...`"))
+            }
+        })?;
+    }
+    Ok(value)
+}
+
+fn expand_float_representable(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`FloatRepresentable` can only be derived for enums",
+        ));
+    };
+    let arms = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let name = &variant.ident;
+            if !variant.fields.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &variant.fields,
+                    "`FloatRepresentable` can only be derived for enums without fields",
+                ));
+            }
+            let value = match variant_value(&variant.attrs)? {
+                Some(value) => quote!(#value),
+                None => quote!(Self::#name as isize as f32),
+            };
+            Ok(quote!(Self::#name => #value,))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let body = if arms.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote!(match self { #(#arms)* })
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::lilt::FloatRepresentable for #name #ty_generics #where_clause {
+            fn float_value(&self) -> f32 {
+                #body
+            }
+        }
+    })
+}
+
+enum FieldMode {
+    Interpolate,
+    Skip,
+    Snap(f32),
+}
+
+fn field_mode(attrs: &[Attribute]) -> syn::Result<FieldMode> {
+    let mut mode = FieldMode::Interpolate;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("lilt")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                mode = FieldMode::Skip;
+                Ok(())
+            } else if meta.path.is_ident("snap") {
+                mode = FieldMode::Snap(parse_f32(meta.value()?)?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip` or `snap = ...`"))
+            }
+        })?;
+    }
+    Ok(mode)
+}
+
+fn container_snap(attrs: &[Attribute]) -> syn::Result<f32> {
+    let mut snap = 0.5;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("lilt")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("snap") {
+                snap = parse_f32(meta.value()?)?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `snap = ...`"))
+            }
+        })?;
+    }
+    Ok(snap)
+}
+
+/// Parses a possibly negative float or integer literal
+fn parse_f32(input: ParseStream) -> syn::Result<f32> {
+    let sign = if input.parse::<Option<Token![-]>>()?.is_some() {
+        -1.
+    } else {
+        1.
+    };
+    let value = match input.parse()? {
+        Lit::Float(float) => float.base10_parse()?,
+        Lit::Int(int) => int.base10_parse::<i32>()? as f32,
+        lit => return Err(syn::Error::new(lit.span(), "expected a number")),
+    };
+    Ok(sign * value)
+}
+
+/// Interpolates a single field given expressions for the origin (a reference) & destination
+fn interpolate_field(
+    mode: &FieldMode,
+    ty: &Type,
+    from: &TokenStream2,
+    to: &TokenStream2,
+    bounds: &mut Vec<TokenStream2>,
+) -> TokenStream2 {
+    match mode {
+        FieldMode::Interpolate => {
+            bounds.push(quote!(#ty: ::lilt::Interpolable));
+            quote!(::lilt::Interpolable::interpolated(#from, #to, ratio))
+        }
+        FieldMode::Skip => quote!(#to),
+        FieldMode::Snap(threshold) => {
+            bounds.push(quote!(#ty: ::core::clone::Clone));
+            quote! {
+                if ratio < #threshold {
+                    ::core::clone::Clone::clone(#from)
+                } else {
+                    #to
+                }
+            }
+        }
+    }
+}
+
+/// Builds a constructor for the given fields from bindings to the origin & destination values
+fn construct(
+    path: TokenStream2,
+    fields: &Fields,
+    from: impl Fn(usize, &Option<Ident>) -> TokenStream2,
+    to: impl Fn(usize, &Option<Ident>) -> TokenStream2,
+    bounds: &mut Vec<TokenStream2>,
+) -> syn::Result<TokenStream2> {
+    let values = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let mode = field_mode(&field.attrs)?;
+            Ok(interpolate_field(
+                &mode,
+                &field.ty,
+                &from(index, &field.ident),
+                &to(index, &field.ident),
+                bounds,
+            ))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => path,
+    })
+}
+
+/// The name bound to a field when destructuring a value
+fn binding(prefix: &str, index: usize, ident: &Option<Ident>) -> Ident {
+    match ident {
+        Some(ident) => format_ident!("{}_{}", prefix, ident),
+        None => format_ident!("{}_{}", prefix, index),
+    }
+}
+
+/// A pattern destructuring every field of a variant into bindings
+fn pattern(path: TokenStream2, fields: &Fields, prefix: &str) -> TokenStream2 {
+    let bindings = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| binding(prefix, index, &field.ident));
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => path,
+    }
+}
+
+fn expand_interpolable(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut bounds = Vec::new();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let value = construct(
+                quote!(Self),
+                &data.fields,
+                |index, ident| match ident {
+                    Some(ident) => quote!(&self.#ident),
+                    None => {
+                        let index = syn::Index::from(index);
+                        quote!(&self.#index)
+                    }
+                },
+                |index, ident| match ident {
+                    Some(ident) => quote!(other.#ident),
+                    None => {
+                        let index = syn::Index::from(index);
+                        quote!(other.#index)
+                    }
+                },
+                &mut bounds,
+            )?;
+            quote! {
+                // Unit structs & skipped fields don't use every argument
+                #[allow(unused_variables)]
+                fn interpolated(&self, other: Self, ratio: f32) -> Self {
+                    #value
+                }
+            }
+        }
+        Data::Enum(data) => {
+            let snap = container_snap(&input.attrs)?;
+            bounds.push(quote!(Self: ::core::clone::Clone));
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let name = &variant.ident;
+                    let from = pattern(quote!(Self::#name), &variant.fields, "__from");
+                    let to = pattern(quote!(Self::#name), &variant.fields, "__to");
+                    let value = construct(
+                        quote!(Self::#name),
+                        &variant.fields,
+                        |index, ident| {
+                            let binding = binding("__from", index, ident);
+                            quote!(#binding)
+                        },
+                        |index, ident| {
+                            let binding = binding("__to", index, ident);
+                            quote!(#binding)
+                        },
+                        &mut bounds,
+                    )?;
+                    Ok(quote!((#from, #to) => #value,))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                #[allow(unreachable_patterns, unused_variables)]
+                fn interpolated(&self, other: Self, ratio: f32) -> Self {
+                    match (self, other) {
+                        #(#arms)*
+                        (_, other) => {
+                            if ratio < #snap {
+                                ::core::clone::Clone::clone(self)
+                            } else {
+                                other
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`Interpolable` can't be derived for unions",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Field bounds are only needed to constrain generic parameters, otherwise they'd leak
+    // private field types into the impl
+    if input.generics.type_params().next().is_none() {
+        bounds.retain(|bound| bound.to_string().starts_with("Self"));
+    }
+    let mut predicates = where_clause
+        .map(|clause| {
+            let predicates = &clause.predicates;
+            quote!(#predicates,)
+        })
+        .unwrap_or_default();
+    predicates.extend(bounds.into_iter().map(|bound| quote!(#bound,)));
+    Ok(quote! {
+        impl #impl_generics ::lilt::Interpolable for #name #ty_generics
+        where
+            #predicates
+        {
+            #body
+        }
+    })
+}
@@ -0,0 +1,179 @@
+use crate::traits::Interpolable;
+use std::f32::consts::{PI, TAU};
+
+/// An angle which interpolates around the circle according to its `Winding`
+///
+/// Interpolating rotations as plain floats takes the long way around when crossing the
+/// wrap point - e.g. 350° to 10° turns 340° backwards rather than 20° forwards.
+/// `Angle` is interpolated using the winding of its destination, which takes the shortest
+/// path by default.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Angle, Animated, Winding};
+/// use std::time::Instant;
+///
+/// let now = Instant::now();
+/// let mut toggle: Animated<bool, Instant> = Animated::new(false);
+/// toggle.transition(true, now);
+/// let rotation = toggle.animate_bool(
+///     Angle::degrees(350.),
+///     Angle::degrees(10.).winding(Winding::Clockwise),
+///     now,
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Angle {
+    radians: f32,
+    winding: Winding,
+}
+
+/// The direction in which an `Angle` is interpolated
+///
+/// Clockwise rotation increases the angle, as in screen coordinates where y points down.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Winding {
+    /// Rotates through the smallest angle, in either direction
+    #[default]
+    Shortest,
+    /// Always rotates clockwise, through less than a full turn
+    Clockwise,
+    /// Always rotates counter-clockwise, through less than a full turn
+    CounterClockwise,
+    /// Interpolates the angles as they are, so every full turn between them is animated
+    Unwrapped,
+}
+
+impl Angle {
+    pub const ZERO: Self = Self::radians(0.);
+
+    /// Creates an angle from radians
+    pub const fn radians(radians: f32) -> Self {
+        Self {
+            radians,
+            winding: Winding::Shortest,
+        }
+    }
+    /// Creates an angle from degrees
+    pub fn degrees(degrees: f32) -> Self {
+        Self::radians(degrees.to_radians())
+    }
+    /// Creates an angle from a number of full turns
+    pub fn turns(turns: f32) -> Self {
+        Self::radians(turns * TAU)
+    }
+    /// Sets the winding used when transitioning to this angle
+    pub fn winding(mut self, winding: Winding) -> Self {
+        self.winding = winding;
+        self
+    }
+    /// The winding used when transitioning to this angle
+    pub fn winding_mode(&self) -> Winding {
+        self.winding
+    }
+    /// The angle in radians
+    pub fn as_radians(&self) -> f32 {
+        self.radians
+    }
+    /// The angle in degrees
+    pub fn as_degrees(&self) -> f32 {
+        self.radians.to_degrees()
+    }
+    /// The angle in full turns
+    pub fn as_turns(&self) -> f32 {
+        self.radians / TAU
+    }
+    /// The equivalent angle within 0...2π radians
+    pub fn normalized(&self) -> Self {
+        Self {
+            radians: self.radians.rem_euclid(TAU),
+            winding: self.winding,
+        }
+    }
+}
+
+impl Interpolable for Angle {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        // Completed transitions end at the destination rather than an equivalent angle
+        if ratio == 1. {
+            return other;
+        }
+        let difference = other.radians - self.radians;
+        let delta = match other.winding {
+            Winding::Shortest => (difference + PI).rem_euclid(TAU) - PI,
+            Winding::Clockwise => difference.rem_euclid(TAU),
+            Winding::CounterClockwise => -(-difference).rem_euclid(TAU),
+            Winding::Unwrapped => difference,
+        };
+        Self {
+            radians: self.radians + delta * ratio,
+            winding: other.winding,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn degrees(from: f32, to: Angle, ratio: f32) -> f32 {
+        Angle::degrees(from).interpolated(to, ratio).as_degrees()
+    }
+
+    fn approximately_equal(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    /// Compares angles in degrees, ignoring full turns
+    fn equivalent(a: f32, b: f32) -> bool {
+        let difference = (a - b).rem_euclid(360.);
+        difference.min(360. - difference) < 1e-3
+    }
+
+    #[test]
+    fn test_units() {
+        assert!(approximately_equal(Angle::degrees(180.).as_radians(), PI));
+        assert!(approximately_equal(Angle::turns(0.25).as_degrees(), 90.));
+        assert!(approximately_equal(Angle::radians(PI).as_turns(), 0.5));
+        assert!(approximately_equal(
+            Angle::degrees(-90.).normalized().as_degrees(),
+            270.
+        ));
+    }
+
+    #[test]
+    fn test_shortest() {
+        let to = Angle::degrees(10.);
+        assert!(equivalent(degrees(350., to, 0.5), 0.));
+        assert!(equivalent(degrees(350., to, 1.), 10.));
+        assert!(equivalent(degrees(30., to, 0.5), 20.));
+        assert!(equivalent(degrees(720., to, 0.5), 5.));
+        assert_eq!(Angle::degrees(350.).interpolated(to, 1.), to);
+    }
+
+    #[test]
+    fn test_directional() {
+        let clockwise = Angle::degrees(10.).winding(Winding::Clockwise);
+        assert!(equivalent(degrees(350., clockwise, 0.5), 0.));
+        assert!(equivalent(degrees(30., clockwise, 0.5), 200.));
+        assert!(equivalent(degrees(10., clockwise, 0.5), 10.));
+
+        let counter_clockwise = Angle::degrees(10.).winding(Winding::CounterClockwise);
+        assert!(equivalent(degrees(350., counter_clockwise, 0.5), 180.));
+        assert!(equivalent(degrees(30., counter_clockwise, 0.5), 20.));
+        assert!(equivalent(degrees(30., counter_clockwise, 1.), 10.));
+        assert_eq!(Angle::degrees(350.).interpolated(clockwise, 1.), clockwise);
+    }
+
+    #[test]
+    fn test_unwrapped() {
+        let to = Angle::turns(2.).winding(Winding::Unwrapped);
+        let mid = Angle::ZERO.interpolated(to, 0.5);
+        assert!(approximately_equal(mid.as_turns(), 1.));
+        assert_eq!(mid.winding_mode(), Winding::Unwrapped);
+        assert!(approximately_equal(degrees(350., to, 0.5), 535.));
+    }
+}
@@ -1,4 +1,9 @@
-use crate::traits::{AnimationTime, FloatRepresentable, Interpolable};
+use crate::keyframes::Keyframes;
+use crate::spring::Spring;
+use crate::traits::{AnimationTime, FloatRepresentable, Interpolable, IntoMilliseconds};
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
 /// Wraps state to enable interpolated transitions
 ///
 /// # Example
@@ -41,6 +46,7 @@ use crate::traits::{AnimationTime, FloatRepresentable, Interpolable};
 /// }
 /// ```
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Animated<T, Time>
 where
     T: FloatRepresentable + Clone + Copy + PartialEq,
@@ -49,6 +55,35 @@ where
     animation: Animation<Time>,
     pub value: T,
     last_value: T,
+    #[cfg_attr(feature = "serde", serde(default = "Vec::new"))]
+    rules: Vec<TransitionRule<T>>,
+    /// The keyframe offset shown when the current transition interrupted another
+    #[cfg_attr(feature = "serde", serde(default))]
+    interrupted_offset: Option<f32>,
+}
+
+/// Overrides applied to transitions between matching states, where `None` matches any state
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct TransitionRule<T> {
+    from: Option<T>,
+    to: Option<T>,
+    overrides: Transition,
+}
+
+impl<T> TransitionRule<T>
+where
+    T: PartialEq,
+{
+    /// How specific the rule is, or `None` if it doesn't match the transition
+    /// Rules matching the destination are more specific than those matching the origin
+    fn specificity(&self, from: &T, to: &T) -> Option<u8> {
+        let matches = |state: &Option<T>, value: &T| state.as_ref().map(|state| state == value);
+        match (matches(&self.from, from), matches(&self.to, to)) {
+            (Some(false), _) | (_, Some(false)) => None,
+            (from, to) => Some(from.is_some() as u8 + 2 * to.is_some() as u8),
+        }
+    }
 }
 
 impl<T, Time> Animated<T, Time>
@@ -57,14 +92,21 @@ where
     Time: AnimationTime,
 {
     /// Creates an animated value with specified animation settings
-    pub fn new_with_settings(value: T, duration_ms: f32, easing: Easing) -> Self {
+    /// Durations are given as a `Duration` or a number of milliseconds
+    pub fn new_with_settings(
+        value: T,
+        duration: impl IntoMilliseconds,
+        easing: impl Into<EasingCurve>,
+    ) -> Self {
         let mut animation = Animation::default(value.float_value());
-        animation.settings.duration_ms = duration_ms;
-        animation.settings.easing = easing;
+        animation.settings.duration_ms = duration.into_milliseconds();
+        animation.settings.easing = easing.into();
         Animated {
             value,
             last_value: value,
             animation,
+            rules: Vec::new(),
+            interrupted_offset: None,
         }
     }
     /// Creates an animated value with a default animation
@@ -73,21 +115,33 @@ where
             value,
             last_value: value,
             animation: Animation::default(value.float_value()),
+            rules: Vec::new(),
+            interrupted_offset: None,
         }
     }
-    /// Specifies the duration of the animation in milliseconds
-    pub fn duration(mut self, duration_ms: f32) -> Self {
-        self.animation.settings.duration_ms = duration_ms;
+    /// Specifies the duration of the animation as a `Duration` or a number of milliseconds
+    /// Replaces any previously specified spring
+    pub fn duration(mut self, duration: impl IntoMilliseconds) -> Self {
+        self.animation.settings.duration_ms = duration.into_milliseconds();
+        self.animation.settings.spring = None;
         self
     }
     /// Specifies the easing with which to animate transitions
-    pub fn easing(mut self, easing: Easing) -> Self {
-        self.animation.settings.easing = easing;
+    /// Replaces any previously specified spring
+    pub fn easing(mut self, easing: impl Into<EasingCurve>) -> Self {
+        self.animation.settings.easing = easing.into();
+        self.animation.settings.spring = None;
         self
     }
-    /// Delays the animation by the given number of milliseconds
-    pub fn delay(mut self, delay_ms: f32) -> Self {
-        self.animation.delay_ms = delay_ms;
+    /// Animates transitions using a spring instead of a duration & easing
+    /// Interrupted springs preserve their velocity
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.animation.settings.spring = Some(spring);
+        self
+    }
+    /// Delays the animation by a `Duration` or a number of milliseconds
+    pub fn delay(mut self, delay: impl IntoMilliseconds) -> Self {
+        self.animation.delay_ms = delay.into_milliseconds();
         self
     }
     /// Repeats animations the specified number of times
@@ -106,52 +160,177 @@ where
         self.animation.auto_reverse_repetitions = true;
         self
     }
+    /// Specifies how in-flight animations behave when interrupted by a new transition
+    pub fn interruption(mut self, interruption: Interruption) -> Self {
+        self.animation.interruption = interruption;
+        self
+    }
+    /// Overrides settings for transitions from one state to another, where `None` matches
+    /// any state
+    ///
+    /// When several rules match a transition, a rule matching its destination is preferred
+    /// over one matching its origin, & a rule matching both is preferred over either.
+    /// Between equally specific rules the last one added is used.
+    ///
+    /// ```rust
+    /// use lilt::{Animated, Spring, Transition};
+    /// use std::time::Instant;
+    ///
+    /// #[derive(Clone, Copy, PartialEq)]
+    /// enum State {
+    ///     Idle,
+    ///     Loading,
+    ///     Error,
+    /// }
+    /// # impl lilt::FloatRepresentable for State {
+    /// #     fn float_value(&self) -> f32 {
+    /// #         *self as u8 as f32
+    /// #     }
+    /// # }
+    ///
+    /// let state: Animated<State, Instant> = Animated::new(State::Idle)
+    ///     .duration(200.)
+    ///     // Any transition to an error bounces
+    ///     .transition_rule(None, Some(State::Error), Transition::new().spring(Spring::bouncy()))
+    ///     // Finishing loading lingers a moment
+    ///     .transition_rule(Some(State::Loading), Some(State::Idle), Transition::new().delay(100.));
+    /// ```
+    pub fn transition_rule(
+        mut self,
+        from: Option<T>,
+        to: Option<T>,
+        overrides: Transition,
+    ) -> Self {
+        self.rules.push(TransitionRule {
+            from,
+            to,
+            overrides,
+        });
+        self
+    }
     /// Begins a transition as soon as the animation is created
     pub fn auto_start(mut self, new_value: T, at: Time) -> Self {
         self.transition(new_value, at);
         self
     }
     /// Applies an alternative duration while animating backwards
-    pub fn asymmetric_duration(mut self, duration_ms: f32) -> Self {
+    pub fn asymmetric_duration(mut self, duration: impl IntoMilliseconds) -> Self {
         self.animation.asymmetric_settings = Some(AnimationSettings {
-            duration_ms,
-            easing: self
-                .animation
-                .asymmetric_settings
-                .map(|a| a.easing)
-                .unwrap_or(self.animation.settings.easing),
+            duration_ms: duration.into_milliseconds(),
+            spring: None,
+            ..self.animation.backwards_settings().clone()
         });
         self
     }
     /// Applies an alternative easing while animating backwards
-    pub fn asymmetric_easing(mut self, easing: Easing) -> Self {
+    pub fn asymmetric_easing(mut self, easing: impl Into<EasingCurve>) -> Self {
         self.animation.asymmetric_settings = Some(AnimationSettings {
-            duration_ms: self
-                .animation
-                .asymmetric_settings
-                .map(|a| a.duration_ms)
-                .unwrap_or(self.animation.settings.duration_ms),
-            easing,
+            easing: easing.into(),
+            spring: None,
+            ..self.animation.backwards_settings().clone()
+        });
+        self
+    }
+    /// Applies an alternative spring while animating backwards
+    pub fn asymmetric_spring(mut self, spring: Spring) -> Self {
+        self.animation.asymmetric_settings = Some(AnimationSettings {
+            spring: Some(spring),
+            ..self.animation.backwards_settings().clone()
         });
         self
     }
     /// Updates the wrapped state & begins an animation
+    /// Uses the overrides of any matching `transition_rule`
     pub fn transition(&mut self, new_value: T, at: Time) {
-        self.transition_internal(new_value, at, false);
+        self.transition_internal(new_value, at, false, None);
+    }
+    /// Updates the wrapped state & begins an animation using the given settings in place
+    /// of those of the animation, for this transition only
+    /// Takes precedence over any matching `transition_rule`
+    pub fn transition_with(&mut self, new_value: T, at: Time, overrides: Transition) {
+        self.transition_internal(new_value, at, false, Some(&overrides));
     }
     /// Updates the wrapped state & instantaneously completes an animation.
-    /// Ignores animation settings such as delay & duration.
+    /// Ignores animation settings such as delay & duration, & emits no `AnimationEvent`s.
     pub fn transition_instantaneous(&mut self, new_value: T, at: Time) {
-        self.transition_internal(new_value, at, true);
-    }
-    fn transition_internal(&mut self, new_value: T, at: Time, instantaneous: bool) {
+        self.transition_internal(new_value, at, true, None);
+    }
+    fn transition_internal(
+        &mut self,
+        new_value: T,
+        at: Time,
+        instantaneous: bool,
+        overrides: Option<&Transition>,
+    ) {
         if self.value != new_value {
+            let overrides =
+                overrides.or_else(|| Self::rule_overrides(&self.rules, &self.value, &new_value));
+            self.interrupted_offset =
+                (!instantaneous && self.in_progress(at)).then(|| self.keyframe_offset(at));
             self.last_value = self.value;
             self.value = new_value;
             self.animation
-                .transition(new_value.float_value(), at, instantaneous)
+                .transition(new_value.float_value(), at, instantaneous, overrides)
         }
     }
+    /// The overrides of the most specific rule matching a transition between the given values
+    fn rule_overrides<'a>(
+        rules: &'a [TransitionRule<T>],
+        value: &T,
+        new_value: &T,
+    ) -> Option<&'a Transition> {
+        rules
+            .iter()
+            .filter_map(|rule| Some((rule.specificity(value, new_value)?, rule)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, rule)| &rule.overrides)
+    }
+    /// Freezes the animation at its current progress until it's resumed
+    /// Transitions made while paused begin once the animation is resumed
+    pub fn pause(&mut self, at: Time) {
+        let playback = Playback {
+            paused: true,
+            ..self.animation.playback
+        };
+        self.animation.set_playback(playback, at);
+    }
+    /// Continues a paused animation from where it left off
+    pub fn resume(&mut self, at: Time) {
+        let playback = Playback {
+            paused: false,
+            ..self.animation.playback
+        };
+        self.animation.set_playback(playback, at);
+    }
+    /// Plays the animation at a multiple of its normal speed from the given time onwards
+    /// Negative rates play the current transition in reverse, back towards its start.
+    /// Later transitions play forwards at the same speed.
+    pub fn set_speed(&mut self, rate: f32, at: Time) {
+        let playback = Playback {
+            rate,
+            ..self.animation.playback
+        };
+        self.animation.set_playback(playback, at);
+    }
+    /// Returns whether the animation is paused
+    pub fn is_paused(&self) -> bool {
+        self.animation.playback.paused
+    }
+    /// The rate at which the animation plays, where 1 is normal speed
+    pub fn speed(&self) -> f32 {
+        self.animation.playback.rate
+    }
+    /// Milestones reached by the current transition after `previous` & up to `now`, in order
+    ///
+    /// Passing the time of the previous frame allows reacting to an animation completing
+    /// without callbacks, e.g. to remove an element once it's faded out.
+    /// Transitions without a duration start & complete together, while instantaneous
+    /// transitions don't emit any events.
+    /// Repeated milestones are collapsed into a single event counting them, so long gaps
+    /// between frames produce at most one event of each kind.
+    pub fn events_between(&self, previous: Time, now: Time) -> Vec<AnimationEvent> {
+        self.animation.events_between(previous, now)
+    }
     /// Returns whether the animation is complete, given the current time
     pub fn in_progress(&self, time: Time) -> bool {
         self.animation.in_progress(time)
@@ -172,24 +351,152 @@ where
         // The only way to do so without storing interpolable values is to represent
         // the interrupt float (origin) as an interpolable value and interpolate between
         // that and the current destination.
+        let interrupt_interpolable =
+            map(self.last_value).interpolated(map(self.value), self.unit_interrupt_value());
+        interrupt_interpolable
+            .interpolated(map(self.value), self.animation.eased_unit_progress(time))
+    }
+    /// Unit progress from the previous value to the current value at which the current
+    /// transition began, which is 0 unless it interrupted another transition
+    fn unit_interrupt_value(&self) -> f32 {
         let interrupted_range = self.value.float_value() - self.last_value.float_value();
-        let unit_interrupt_value = if interrupted_range == 0. {
+        if interrupted_range == 0. {
             0.
         } else {
             (self.animation.origin - self.last_value.float_value()) / interrupted_range
+        }
+    }
+    /// Samples keyframes at the linear progress of the current transition, so that each
+    /// transition runs through every stop using only the easings of the keyframes
+    ///
+    /// A transition which interrupts another continues from the offset shown at the
+    /// interruption, blending into a path which begins at the fraction of the way to its
+    /// destination that had already been covered.
+    pub fn animate_keyframes<I>(&self, keyframes: &Keyframes<I>, time: Time) -> I
+    where
+        I: Interpolable + Clone,
+    {
+        keyframes.value(self.keyframe_offset(time))
+    }
+    fn keyframe_offset(&self, time: Time) -> f32 {
+        let progress = match self.animation.transition_time {
+            Some(_) => self.animation.linear_unit_progress(time),
+            None => 1.,
         };
-        let interrupt_interpolable =
-            map(self.last_value).interpolated(map(self.value), unit_interrupt_value);
-        interrupt_interpolable
-            .interpolated(map(self.value), self.animation.eased_unit_progress(time))
+        let start = self.unit_interrupt_value().clamp(0., 1.);
+        let offset = start + (1. - start) * progress;
+        match self.interrupted_offset {
+            Some(shown) => shown + (offset - shown) * progress,
+            None => offset,
+        }
     }
-    // Just for nicer testing
-    #[allow(dead_code)]
-    fn linear_progress(&self, time: Time) -> f32 {
+    /// Begins a transition from 0 to 1 on the float axis, for values which track their own
+    /// origin & destination
+    /// The velocity of any in-flight transition is carried over, multiplied by the ratio of
+    /// the interrupted transition's span to the new transition's span.
+    pub(crate) fn restart(
+        &mut self,
+        at: Time,
+        instantaneous: bool,
+        overrides: Option<&Transition>,
+        span_ratio: f32,
+    ) {
+        self.animation
+            .restart(at, instantaneous, overrides, span_ratio);
+    }
+    /// Delays transitions by the given milliseconds of time at the current playback speed
+    pub(crate) fn offset_delay(&mut self, offset_ms: f32) {
+        self.animation.delay_ms += offset_ms * self.speed().abs();
+    }
+    pub(crate) fn delay_ms(&self) -> f32 {
+        self.animation.delay_ms
+    }
+    /// Begins a transition using the given delay in place of the configured delay,
+    /// together with the overrides of any matching `transition_rule`
+    pub(crate) fn transition_delayed(&mut self, new_value: T, at: Time, delay_ms: f32) {
+        let mut overrides = Self::rule_overrides(&self.rules, &self.value, &new_value)
+            .cloned()
+            .unwrap_or_default();
+        overrides.delay_ms = Some(delay_ms);
+        self.transition_internal(new_value, at, false, Some(&overrides));
+    }
+    /// Duration in milliseconds of a transition in the given direction, excluding delay
+    pub(crate) fn transition_duration(&self, backwards: bool) -> f32 {
+        let animation = &self.animation;
+        if animation.repeat_forever {
+            f32::INFINITY
+        } else if animation.auto_reverse_repetitions {
+            animation.total_duration()
+        } else if backwards {
+            animation.backwards_settings().duration_ms(0.) * animation.repetitions as f32
+        } else {
+            animation.settings.duration_ms(0.) * animation.repetitions as f32
+        }
+    }
+    /// Duration of the current (or next) transition in milliseconds, including delay
+    /// Accounts for the playback speed, so paused animations have an infinite duration
+    pub(crate) fn total_duration(&self) -> f32 {
+        let rate = self.animation.playback.rate().abs();
+        if self.animation.repeat_forever || rate == 0. {
+            f32::INFINITY
+        } else {
+            (self.animation.delay_ms + self.animation.total_duration()) / rate
+        }
+    }
+    /// Anchors each transition at the given time, preserving its elapsed time
+    #[cfg(feature = "serde")]
+    pub(crate) fn rebase(&mut self, time: Time) {
+        self.animation.rebase(time);
+    }
+    /// Replaces the time type, mapping each transition time & the milliseconds
+    /// the transition had been running for at that time
+    #[cfg(feature = "serde")]
+    pub(crate) fn map_time<U>(self, map: &impl Fn(Time, f64) -> (U, f64)) -> Animated<T, U>
+    where
+        U: AnimationTime,
+    {
+        Animated {
+            animation: self.animation.map_time(map),
+            value: self.value,
+            last_value: self.last_value,
+            rules: self.rules,
+            interrupted_offset: self.interrupted_offset,
+        }
+    }
+    /// Moves the current transition to the given unit progress through its duration,
+    /// continuing from there at the given time
+    /// Animations which repeat forever seek within a single repetition (or reversal).
+    /// Transitions interrupted with `Interruption::Additive` are moved to the same progress.
+    /// Before the first transition the value is already at rest, so seeking has no effect.
+    pub fn seek_to_progress(&mut self, unit_progress: f32, at: Time) {
+        self.animation.seek(unit_progress, at);
+    }
+    /// Holds the current transition at the given unit progress until it's resumed,
+    /// allowing it to be driven by an external controller such as a scrubber
+    pub fn hold_at_progress(&mut self, unit_progress: f32, at: Time) {
+        self.seek_to_progress(unit_progress, at);
+        self.pause(at);
+    }
+    /// Unit progress through the duration of the current transition, excluding delay
+    /// This is the inverse of `seek_to_progress` & is 1 once the transition is complete
+    pub fn progress_at(&self, time: Time) -> f32 {
+        self.animation.unit_progress(time)
+    }
+    /// Unit progress through the current transition with its easing applied, which is
+    /// the progress `animate` interpolates by from where the transition began
+    pub fn eased_progress_at(&self, time: Time) -> f32 {
+        self.animation.eased_unit_progress(time)
+    }
+    /// The linear position of the animation along the float axis of the wrapped value,
+    /// between `float_value` of the previous & current value
+    /// Use `progress_at` for unit progress through the transition.
+    pub fn linear_progress(&self, time: Time) -> f32 {
         self.animation.linear_progress(time)
     }
-    #[allow(dead_code)]
-    fn eased_progress(&self, time: Time) -> f32 {
+    /// The eased position of the animation along the float axis of the wrapped value,
+    /// between `float_value` of the previous & current value
+    /// Use `eased_progress_at` for unit progress through the transition.
+    pub fn eased_progress(&self, time: Time) -> f32 {
         self.animation.eased_progress(time)
     }
 }
@@ -218,6 +525,39 @@ where
     }
 }
 
+impl<T, Time> Animated<T, Time>
+where
+    T: FloatRepresentable + Clone + Copy + PartialEq,
+    Time: AnimationTime + Add<Duration, Output = Time>,
+{
+    /// When the animated value will next change, given the current time
+    ///
+    /// Animations which are waiting out a delay, or holding between the jumps of
+    /// `Easing::Steps`, can schedule a redraw for a later time rather than every frame.
+    pub fn next_change_at(&self, now: Time) -> NextChange<Time> {
+        let animation = &self.animation;
+        let rate = animation.playback.rate() as f64;
+        if rate == 0. || !animation.in_progress(now) {
+            return NextChange::Idle;
+        }
+        if rate < 0. || animation.layers.iter().any(|layer| layer.in_progress(now)) {
+            return NextChange::Continuous;
+        }
+        match animation
+            .elapsed(now)
+            .and_then(|elapsed| animation.playback_until_change(elapsed))
+        {
+            Some(playback_ms) => NextChange::At(now + milliseconds(playback_ms / rate)),
+            None => NextChange::Continuous,
+        }
+    }
+    /// When the current transition will complete at its current speed
+    /// Returns `None` if there's no transition, it repeats forever, or it's paused
+    pub fn settles_at(&self) -> Option<Time> {
+        self.animation.settles_at()
+    }
+}
+
 impl<T, Time> Animated<T, Time>
 where
     T: FloatRepresentable + Clone + Copy + PartialEq,
@@ -251,7 +591,8 @@ where
     }
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Animation<Time>
 where
     Time: AnimationTime,
@@ -265,14 +606,205 @@ where
     auto_reverse_repetitions: bool,
     repeat_forever: bool,
     transition_time: Option<Time>,
+    /// Milliseconds the transition had already been running for at `transition_time`
+    time_offset_ms: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    playback: Playback,
+    /// The configured settings, while those of the current transition are overridden
+    #[cfg_attr(feature = "serde", serde(default))]
+    overridden: Option<Box<OverriddenSettings>>,
+    /// Unit progress per millisecond at the beginning of the transition
+    velocity: f32,
+    interruption: Interruption,
+    /// Interrupted animations which are still contributing to the current value
+    layers: Vec<Animation<Time>>,
+}
+
+/// When an animated value will next change, see `Animated::next_change_at`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NextChange<Time> {
+    /// The value is changing continuously & should be redrawn every frame
+    Continuous,
+    /// The value won't change until the given time
+    At(Time),
+    /// The value is at rest & won't change until the next transition or playback change
+    Idle,
+}
+
+/// A milestone reached by a transition, see `Animated::events_between`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationEvent {
+    /// The transition began animating, after any delay
+    Started,
+    /// An auto-reversing animation reached its destination & began animating back,
+    /// the given number of times
+    Reversed(u64),
+    /// A repetition completed & the next began, the given number of times
+    RepetitionCompleted(u64),
+    /// The transition reached its end
+    Completed,
+}
+
+/// Defines how an in-flight animation behaves when a new transition interrupts it
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interruption {
+    /// Begins the new transition from the current value, discarding the velocity
+    /// of the interrupted animation (unless it's a spring)
+    #[default]
+    Restart,
+    /// Lets interrupted animations run to completion while the new transition is
+    /// added on top of them, so rapid retargeting stays continuous.
+    ///
+    /// Animations which repeat can't be blended & always restart.
+    Additive,
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct AnimationSettings {
     duration_ms: f32,
-    easing: Easing,
+    easing: EasingCurve,
+    spring: Option<Spring>,
 }
 
+impl AnimationSettings {
+    fn duration_ms(&self, velocity: f32) -> f32 {
+        match self.spring {
+            Some(spring) => spring.settling_duration_ms(velocity),
+            None => self.duration_ms,
+        }
+    }
+
+    fn eased(&self, progress: f32, velocity: f32) -> f32 {
+        match self.spring {
+            Some(_) if progress >= 1. => 1.,
+            Some(spring) => {
+                spring
+                    .solve(progress * spring.settling_duration_ms(velocity), velocity)
+                    .0
+            }
+            None => self.easing.value(progress),
+        }
+    }
+}
+
+/// Settings which are restored once a transition with overrides is replaced
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct OverriddenSettings {
+    settings: AnimationSettings,
+    asymmetric_settings: Option<AnimationSettings>,
+    delay_ms: f32,
+}
+
+/// Settings which replace those of an `Animated` for a single transition
+///
+/// Overrides apply whichever direction the transition animates in, & the configured
+/// settings are used again by the next transition.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Animated, Easing, Transition};
+/// use std::time::Instant;
+///
+/// let mut scroll: Animated<f32, Instant> = Animated::new(0.).duration(300.);
+/// // A programmatic scroll takes its time
+/// scroll.transition_with(
+///     500.,
+///     Instant::now(),
+///     Transition::new().duration(800.).easing(Easing::EaseInOutCubic),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transition {
+    timing: Option<TransitionTiming>,
+    delay_ms: Option<f32>,
+}
+
+/// The timing overridden by a `Transition`, where the most recently specified timing wins
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum TransitionTiming {
+    /// Overrides the duration and/or easing, replacing any configured spring
+    Curve {
+        duration_ms: Option<f32>,
+        easing: Option<EasingCurve>,
+    },
+    Spring(Spring),
+}
+
+impl Transition {
+    /// Creates overrides which keep every configured setting
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Overrides the duration with a `Duration` or a number of milliseconds
+    /// Replaces any configured or previously specified spring
+    pub fn duration(mut self, duration: impl IntoMilliseconds) -> Self {
+        let duration_ms = Some(duration.into_milliseconds());
+        self.timing = Some(match self.timing.take() {
+            Some(TransitionTiming::Curve { easing, .. }) => TransitionTiming::Curve {
+                duration_ms,
+                easing,
+            },
+            _ => TransitionTiming::Curve {
+                duration_ms,
+                easing: None,
+            },
+        });
+        self
+    }
+    /// Overrides the easing
+    /// Replaces any configured or previously specified spring
+    pub fn easing(mut self, easing: impl Into<EasingCurve>) -> Self {
+        let easing = Some(easing.into());
+        self.timing = Some(match self.timing.take() {
+            Some(TransitionTiming::Curve { duration_ms, .. }) => TransitionTiming::Curve {
+                duration_ms,
+                easing,
+            },
+            _ => TransitionTiming::Curve {
+                duration_ms: None,
+                easing,
+            },
+        });
+        self
+    }
+    /// Animates the transition using a spring instead of a duration & easing
+    /// Replaces any previously specified duration or easing
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.timing = Some(TransitionTiming::Spring(spring));
+        self
+    }
+    /// Overrides the delay with a `Duration` or a number of milliseconds
+    pub fn delay(mut self, delay: impl IntoMilliseconds) -> Self {
+        self.delay_ms = Some(delay.into_milliseconds());
+        self
+    }
+    fn apply(&self, settings: &mut AnimationSettings) {
+        match &self.timing {
+            Some(TransitionTiming::Curve {
+                duration_ms,
+                easing,
+            }) => {
+                settings.duration_ms = duration_ms.unwrap_or(settings.duration_ms);
+                if let Some(easing) = easing {
+                    settings.easing = easing.clone();
+                }
+                settings.spring = None;
+            }
+            Some(TransitionTiming::Spring(spring)) => settings.spring = Some(*spring),
+            None => {}
+        }
+    }
+}
+
+/// Duration in milliseconds over which velocity is sampled at an interruption
+const VELOCITY_SAMPLE_MS: f32 = 0.5;
+
 impl<Time> Animation<Time>
 where
     Time: AnimationTime,
@@ -283,7 +815,8 @@ where
             destination: origin,
             settings: AnimationSettings {
                 duration_ms: 100.,
-                easing: Easing::EaseInOut,
+                easing: Easing::EaseInOut.into(),
+                spring: None,
             },
             asymmetric_settings: None,
             delay_ms: 0.,
@@ -291,60 +824,423 @@ where
             auto_reverse_repetitions: false,
             repeat_forever: false,
             transition_time: None,
+            time_offset_ms: 0.,
+            playback: Playback::default(),
+            overridden: None,
+            velocity: 0.,
+            interruption: Interruption::Restart,
+            layers: Vec::new(),
         }
     }
 
-    fn transition(&mut self, destination: f32, time: Time, instantaneous: bool) {
+    #[cfg(feature = "serde")]
+    fn map_time<U>(self, map: &impl Fn(Time, f64) -> (U, f64)) -> Animation<U>
+    where
+        U: AnimationTime,
+    {
+        let (transition_time, time_offset_ms) = match self.transition_time {
+            Some(time) => {
+                let (time, offset) = map(time, self.time_offset_ms);
+                (Some(time), offset)
+            }
+            None => (None, self.time_offset_ms),
+        };
+        Animation {
+            origin: self.origin,
+            destination: self.destination,
+            delay_ms: self.delay_ms,
+            settings: self.settings,
+            asymmetric_settings: self.asymmetric_settings,
+            repetitions: self.repetitions,
+            auto_reverse_repetitions: self.auto_reverse_repetitions,
+            repeat_forever: self.repeat_forever,
+            transition_time,
+            time_offset_ms,
+            playback: self.playback,
+            overridden: self.overridden,
+            velocity: self.velocity,
+            interruption: self.interruption,
+            layers: self
+                .layers
+                .into_iter()
+                .map(|layer| layer.map_time(map))
+                .collect(),
+        }
+    }
+
+    fn backwards_settings(&self) -> &AnimationSettings {
+        self.asymmetric_settings.as_ref().unwrap_or(&self.settings)
+    }
+
+    fn transition(
+        &mut self,
+        destination: f32,
+        time: Time,
+        instantaneous: bool,
+        overrides: Option<&Transition>,
+    ) {
         if self.destination != destination {
             if instantaneous {
                 self.origin = destination;
                 self.destination = destination;
+                self.velocity = 0.;
+                self.layers.clear();
+                self.apply_overrides(None);
+                // Without a transition there are no milestones left to report
+                self.transition_time = None;
+                self.time_offset_ms = 0.;
                 return;
             }
-            if self.in_progress(time) {
+            if self.interruption == Interruption::Additive
+                && !self.repeats()
+                && self.in_progress(time)
+            {
+                // The interrupted animation keeps running as an offset from its destination,
+                // while the new transition starts from that destination.
+                let mut layers = std::mem::take(&mut self.layers);
+                layers.push(self.clone());
+                layers.retain(|layer| layer.in_progress(time));
+                self.layers = layers;
+                self.origin = self.destination;
+                self.velocity = 0.;
+            } else if self.in_progress(time) {
+                let velocity = self.unit_velocity(time) * self.progress_range();
                 let eased_progress = self.eased_progress(time);
                 self.origin = eased_progress;
+                self.velocity = if destination == eased_progress {
+                    0.
+                } else {
+                    velocity / (destination - eased_progress)
+                };
+                self.layers.clear();
             } else {
                 self.origin = self.destination;
+                self.velocity = 0.;
+                self.layers.clear();
             }
+            self.play_forwards(time);
+            // The interrupted animation has been sampled, so its settings can be replaced
+            self.apply_overrides(overrides);
             self.transition_time = Some(time);
+            self.time_offset_ms = 0.;
             self.destination = destination;
         }
     }
 
+    fn restart(
+        &mut self,
+        time: Time,
+        instantaneous: bool,
+        overrides: Option<&Transition>,
+        span_ratio: f32,
+    ) {
+        self.velocity = if !instantaneous && self.in_progress(time) {
+            self.unit_velocity(time) * self.progress_range() * span_ratio
+        } else {
+            0.
+        };
+        self.origin = 0.;
+        self.destination = 1.;
+        self.layers.clear();
+        if !instantaneous {
+            self.play_forwards(time);
+        }
+        self.apply_overrides(overrides);
+        self.transition_time = if instantaneous { None } else { Some(time) };
+        self.time_offset_ms = 0.;
+    }
+
+    /// Plays a new transition forwards if playback was reversed, at the same speed
+    /// Reversing only applies to the transition being played when the speed was set
+    fn play_forwards(&mut self, time: Time) {
+        if self.playback.rate >= 0. {
+            return;
+        }
+        // Velocity is measured in playback time, which now runs the other way
+        self.velocity = -self.velocity;
+        let playback = Playback {
+            rate: -self.playback.rate,
+            ..self.playback
+        };
+        self.set_playback(playback, time);
+    }
+
+    /// Restores the configured settings, then applies any overrides for the next transition
+    fn apply_overrides(&mut self, overrides: Option<&Transition>) {
+        if let Some(overridden) = self.overridden.take() {
+            self.settings = overridden.settings;
+            self.asymmetric_settings = overridden.asymmetric_settings;
+            self.delay_ms = overridden.delay_ms;
+        }
+        let Some(overrides) = overrides else {
+            return;
+        };
+        self.overridden = Some(Box::new(OverriddenSettings {
+            settings: self.settings.clone(),
+            asymmetric_settings: self.asymmetric_settings.clone(),
+            delay_ms: self.delay_ms,
+        }));
+        if let Some(delay_ms) = overrides.delay_ms {
+            self.delay_ms = delay_ms;
+        }
+        overrides.apply(&mut self.settings);
+        if let Some(asymmetric_settings) = &mut self.asymmetric_settings {
+            overrides.apply(asymmetric_settings);
+        }
+    }
+
     fn current_progress(&self, time: Time) -> Progress {
-        let Some(transition_time) = self.transition_time else {
+        let Some(elapsed) = self.elapsed(time) else {
             return Progress {
                 linear_unit_progress: 0.,
                 eased_unit_progress: 0.,
                 complete: true,
             };
         };
-        let elapsed = f32::max(0., time.elapsed_since(transition_time) - self.delay_ms);
+        self.progress_at(elapsed)
+    }
+
+    fn events_between(&self, previous: Time, now: Time) -> Vec<AnimationEvent> {
+        let (Some(previous), Some(now)) = (self.elapsed(previous), self.elapsed(now)) else {
+            return Vec::new();
+        };
+        let mut events = Vec::new();
+        let delay_ms = self.delay_ms as f64;
+        let started = previous <= delay_ms && now > delay_ms;
+        if started {
+            events.push(AnimationEvent::Started);
+        }
+        let segments = if self.auto_reverse_repetitions {
+            self.repetitions as u64 * 2 + 1
+        } else {
+            self.repetitions as u64
+        };
+        let first = self.segment(previous) + 1;
+        let last = if self.repeat_forever {
+            self.segment(now)
+        } else {
+            u64::min(self.segment(now), segments.saturating_sub(1))
+        };
+        if first <= last && self.auto_reverse_repetitions {
+            // Odd segments animate back, so reversals & repetitions alternate
+            let odd_segments = |up_to: u64| up_to.div_ceil(2);
+            let reversals = odd_segments(last) - odd_segments(first - 1);
+            let repetitions = last - first + 1 - reversals;
+            let reversed = (reversals > 0).then_some(AnimationEvent::Reversed(reversals));
+            let repeated =
+                (repetitions > 0).then_some(AnimationEvent::RepetitionCompleted(repetitions));
+            if first % 2 == 1 {
+                events.extend(reversed.into_iter().chain(repeated));
+            } else {
+                events.extend(repeated.into_iter().chain(reversed));
+            }
+        } else if first <= last {
+            events.push(AnimationEvent::RepetitionCompleted(last - first + 1));
+        }
+        // Transitions without a duration are complete throughout, & complete as they start
+        let completed = if self.total_duration() == 0. {
+            started
+        } else {
+            !self.progress_at(previous).complete && self.progress_at(now).complete
+        };
+        if completed {
+            events.push(AnimationEvent::Completed);
+        }
+        events
+    }
+
+    /// Milliseconds of playback until the value next changes, if it's waiting out
+    /// a delay or holding between the jumps of `Easing::Steps`
+    fn playback_until_change(&self, elapsed: f64) -> Option<f64> {
+        let delay_ms = self.delay_ms as f64;
+        if elapsed < delay_ms {
+            return Some(delay_ms - elapsed);
+        }
+        let elapsed = elapsed - delay_ms;
+        let forward_duration = self.settings.duration_ms(self.velocity) as f64;
+        let backward_duration = self.backwards_settings().duration_ms(self.velocity) as f64;
+        let (settings, start, duration) = if self.auto_reverse_repetitions {
+            let cycle = forward_duration + backward_duration;
+            if cycle <= 0. {
+                return None;
+            }
+            let cycle_start = elapsed - elapsed % cycle;
+            if elapsed - cycle_start < forward_duration {
+                (&self.settings, cycle_start, forward_duration)
+            } else {
+                (
+                    self.backwards_settings(),
+                    cycle_start + forward_duration,
+                    backward_duration,
+                )
+            }
+        } else if self.destination < self.origin {
+            (self.backwards_settings(), 0., backward_duration)
+        } else {
+            (&self.settings, 0., forward_duration)
+        };
+        let (None, EasingCurve::Easing(Easing::Steps(steps, _))) =
+            (settings.spring, &settings.easing)
+        else {
+            return None;
+        };
+        // Repetitions span whole steps, so jumps are aligned to the start of the segment
+        let step_duration = duration / (*steps).max(1) as f64;
+        if step_duration <= 0. {
+            return None;
+        }
+        let elapsed = elapsed - start;
+        Some(((elapsed / step_duration).floor() + 1.) * step_duration - elapsed)
+    }
+
+    /// Index of the repetition, or reversal, being played after the given milliseconds
+    /// of playback
+    fn segment(&self, elapsed: f64) -> u64 {
+        let elapsed = f64::max(0., elapsed - self.delay_ms as f64);
+        let forward_duration = self.settings.duration_ms(self.velocity) as f64;
+        let backward_duration = self.backwards_settings().duration_ms(self.velocity) as f64;
+        if self.auto_reverse_repetitions {
+            let cycle = forward_duration + backward_duration;
+            if cycle <= 0. {
+                return 0;
+            }
+            let cycles = (elapsed / cycle).floor();
+            let reversing = elapsed - cycles * cycle >= forward_duration;
+            cycles as u64 * 2 + reversing as u64
+        } else {
+            let duration = if self.destination < self.origin {
+                backward_duration
+            } else {
+                forward_duration
+            };
+            if duration <= 0. {
+                return 0;
+            }
+            (elapsed / duration).floor() as u64
+        }
+    }
+
+    /// Milliseconds of playback since the transition began, if there's been a transition
+    /// Kept as an `f64` so that long running animations don't lose precision
+    fn elapsed(&self, time: Time) -> Option<f64> {
+        self.transition_time.map(|transition_time| {
+            time.seconds_since(transition_time) * 1000. * self.playback.rate() as f64
+                + self.time_offset_ms
+        })
+    }
+
+    /// Anchors the transition at the given time, so that changes to playback only
+    /// affect the animation from that time onwards
+    fn rebase(&mut self, time: Time) {
+        if let Some(elapsed) = self.elapsed(time) {
+            // Time spent beyond either end of the animation would otherwise need to be
+            // played back before reversing had any visible effect
+            let end = if self.repeat_forever {
+                f64::INFINITY
+            } else {
+                (self.delay_ms + self.total_duration()) as f64
+            };
+            self.time_offset_ms = elapsed.clamp(0., end);
+            self.transition_time = Some(time);
+        }
+        for layer in &mut self.layers {
+            layer.rebase(time);
+        }
+    }
+
+    fn set_playback(&mut self, playback: Playback, time: Time) {
+        self.rebase(time);
+        self.playback = playback;
+        for layer in &mut self.layers {
+            layer.playback = playback;
+        }
+    }
+
+    /// Animations which repeat forever only depend on their phase, so elapsed time is
+    /// wrapped to a single period before it's narrowed to an `f32`
+    fn phase(&self, elapsed: f64) -> f32 {
+        let period = self.period() as f64;
+        if self.repeat_forever && period > 0. {
+            return (elapsed % period) as f32;
+        }
+        elapsed as f32
+    }
+
+    /// Milliseconds spanned by the transition excluding delay, or a single period
+    /// for animations which repeat forever
+    fn period(&self) -> f32 {
+        if !self.repeat_forever {
+            return self.total_duration();
+        }
+        let forward_duration = self.settings.duration_ms(self.velocity);
+        let backward_duration = self.backwards_settings().duration_ms(self.velocity);
+        if self.auto_reverse_repetitions {
+            forward_duration + backward_duration
+        } else if self.destination < self.origin {
+            backward_duration
+        } else {
+            forward_duration
+        }
+    }
+
+    fn seek(&mut self, unit_progress: f32, time: Time) {
+        if self.transition_time.is_none() {
+            return;
+        }
+        self.rebase(time);
+        self.time_offset_ms = (self.delay_ms + unit_progress.clamp(0., 1.) * self.period()) as f64;
+        // Interrupted transitions still playing additively move along with the transition
+        for layer in &mut self.layers {
+            layer.seek(unit_progress, time);
+        }
+    }
+
+    fn unit_progress(&self, time: Time) -> f32 {
+        let period = self.period();
+        match self.elapsed(time) {
+            Some(elapsed) if period > 0. => {
+                let elapsed = self.phase(f64::max(0., elapsed - self.delay_ms as f64));
+                f32::min(1., elapsed / period)
+            }
+            _ => 1.,
+        }
+    }
+
+    fn progress_at(&self, elapsed: f64) -> Progress {
+        if self.playback.rate < 0. && elapsed <= self.delay_ms as f64 {
+            // Playing in reverse completes once the start has been reached
+            return Progress {
+                linear_unit_progress: 0.,
+                eased_unit_progress: 0.,
+                complete: true,
+            };
+        }
+        let elapsed = self.phase(f64::max(0., elapsed - self.delay_ms as f64));
 
         let settings;
         let elapsed_current;
         let auto_reversing;
 
         if self.auto_reverse_repetitions {
-            let asymmetry = self.asymmetric_settings.unwrap_or(self.settings);
-            let combined_durations = self.settings.duration_ms + asymmetry.duration_ms;
-            let first_animation = elapsed % combined_durations - self.settings.duration_ms < 0.;
+            let asymmetry = self.backwards_settings();
+            let forward_duration = self.settings.duration_ms(self.velocity);
+            let combined_durations = forward_duration + asymmetry.duration_ms(self.velocity);
+            let first_animation = elapsed % combined_durations - forward_duration < 0.;
             if first_animation {
                 elapsed_current = elapsed % combined_durations;
-                settings = self.settings;
+                settings = &self.settings;
                 auto_reversing = false;
             } else {
                 settings = asymmetry;
-                elapsed_current = elapsed % combined_durations - self.settings.duration_ms;
+                elapsed_current = elapsed % combined_durations - forward_duration;
                 auto_reversing = true;
             }
         } else if self.destination < self.origin {
-            settings = self.asymmetric_settings.unwrap_or(self.settings);
+            settings = self.backwards_settings();
             elapsed_current = elapsed;
             auto_reversing = false;
         } else {
-            settings = self.settings;
+            settings = &self.settings;
             elapsed_current = elapsed;
             auto_reversing = false;
         }
@@ -353,38 +1249,64 @@ where
         if total_duration == 0. {
             return Progress {
                 linear_unit_progress: 1.,
-                eased_unit_progress: settings.easing.value(1.),
+                eased_unit_progress: settings.eased(1., self.velocity),
                 complete: true,
             };
         }
 
         let complete = !self.repeat_forever && elapsed >= total_duration;
-        let repeat = elapsed_current / settings.duration_ms;
+        let repeat = elapsed_current / settings.duration_ms(self.velocity);
         let progress = if complete { 1. } else { repeat % 1. };
         if auto_reversing && !complete {
             Progress {
                 linear_unit_progress: 1. - progress,
-                eased_unit_progress: settings.easing.value(1. - progress),
+                eased_unit_progress: settings.eased(1. - progress, self.velocity),
                 complete,
             }
         } else {
             Progress {
                 linear_unit_progress: progress,
-                eased_unit_progress: settings.easing.value(progress),
+                eased_unit_progress: settings.eased(progress, self.velocity),
                 complete,
             }
         }
     }
 
+    /// Unit progress per millisecond at the given time
+    fn unit_velocity(&self, time: Time) -> f32 {
+        let Some(elapsed) = self.elapsed(time) else {
+            return 0.;
+        };
+        let before = self.progress_at(elapsed - VELOCITY_SAMPLE_MS as f64);
+        let after = self.progress_at(elapsed + VELOCITY_SAMPLE_MS as f64);
+        (after.eased_unit_progress - before.eased_unit_progress) / (2. * VELOCITY_SAMPLE_MS)
+    }
+
     fn linear_unit_progress(&self, time: Time) -> f32 {
         self.current_progress(time).linear_unit_progress
     }
 
     fn eased_unit_progress(&self, time: Time) -> f32 {
-        self.current_progress(time).eased_unit_progress
+        let progress = self.current_progress(time).eased_unit_progress;
+        let range = self.progress_range();
+        if self.layers.is_empty() || range == 0. {
+            return progress;
+        }
+        let offset: f32 = self
+            .layers
+            .iter()
+            .map(|layer| layer.eased_progress(time) - layer.destination)
+            .sum();
+        progress + offset / range
+    }
+
+    fn repeats(&self) -> bool {
+        self.repeat_forever || self.auto_reverse_repetitions || self.repetitions > 1
     }
 
     fn total_duration(&self) -> f32 {
+        let forward_duration = self.settings.duration_ms(self.velocity);
+        let backward_duration = self.backwards_settings().duration_ms(self.velocity);
         let true_repetitions = if self.auto_reverse_repetitions {
             (self.repetitions * 2) + 1
         } else {
@@ -392,28 +1314,17 @@ where
         } as f32;
         if true_repetitions > 1. {
             if true_repetitions % 2. == 0. {
-                self.settings.duration_ms * (true_repetitions * 0.5)
-                    + self
-                        .asymmetric_settings
-                        .unwrap_or(self.settings)
-                        .duration_ms
-                        * (true_repetitions * 0.5)
+                forward_duration * (true_repetitions * 0.5)
+                    + backward_duration * (true_repetitions * 0.5)
             } else {
-                self.settings.duration_ms * ((true_repetitions - true_repetitions % 2.) * 0.5)
-                    + self
-                        .asymmetric_settings
-                        .unwrap_or(self.settings)
-                        .duration_ms
-                        * ((true_repetitions - true_repetitions % 2.) * 0.5)
-                    + self.settings.duration_ms
+                forward_duration * ((true_repetitions - true_repetitions % 2.) * 0.5)
+                    + backward_duration * ((true_repetitions - true_repetitions % 2.) * 0.5)
+                    + forward_duration
             }
         } else if self.destination < self.origin {
-            self.asymmetric_settings
-                .unwrap_or(self.settings)
-                .duration_ms
-                * true_repetitions
+            backward_duration * true_repetitions
         } else {
-            self.settings.duration_ms * true_repetitions
+            forward_duration * true_repetitions
         }
     }
 
@@ -431,9 +1342,75 @@ where
 
     fn in_progress(&self, time: Time) -> bool {
         !self.current_progress(time).complete
+            || self.layers.iter().any(|layer| layer.in_progress(time))
+    }
+}
+
+/// Maps time onto the elapsed time of an animation
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Playback {
+    rate: f32,
+    paused: bool,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Playback {
+            rate: 1.,
+            paused: false,
+        }
+    }
+}
+
+impl Playback {
+    /// Milliseconds of playback per millisecond of time
+    fn rate(&self) -> f32 {
+        if self.paused {
+            0.
+        } else {
+            self.rate
+        }
+    }
+}
+
+impl<Time> Animation<Time>
+where
+    Time: AnimationTime + Add<Duration, Output = Time>,
+{
+    fn settles_at(&self) -> Option<Time> {
+        let transition_time = self.transition_time?;
+        let rate = self.playback.rate() as f64;
+        if self.repeat_forever || rate == 0. {
+            return None;
+        }
+        let end = if rate > 0. {
+            self.delay_ms + self.total_duration()
+        } else {
+            self.delay_ms
+        } as f64;
+        let remaining = f64::max(0., (end - self.time_offset_ms) / rate);
+        let settles_at = transition_time + milliseconds(remaining);
+        // Interrupted animations which are still running settle independently
+        let latest = self
+            .layers
+            .iter()
+            .filter_map(|layer| layer.settles_at())
+            .fold(settles_at, |latest, time| {
+                if time.seconds_since(latest) > 0. {
+                    time
+                } else {
+                    latest
+                }
+            });
+        Some(latest)
     }
 }
 
+fn milliseconds(milliseconds: f64) -> Duration {
+    Duration::from_secs_f64(milliseconds / 1000.)
+}
+
 struct Progress {
     linear_unit_progress: f32,
     eased_unit_progress: f32,
@@ -474,9 +1451,95 @@ pub enum Easing {
     EaseInBounce,
     EaseOutBounce,
     EaseInOutBounce,
+    /// A cubic bézier curve from (0, 0) to (1, 1) with the control points (x1, y1) & (x2, y2),
+    /// matching the CSS `cubic-bezier()` timing function
+    CubicBezier(f32, f32, f32, f32),
+    /// Jumps between the given number of equal steps, matching the CSS `steps()` timing function
+    Steps(u32, StepPosition),
     Custom(fn(f32) -> f32),
 }
 
+/// Any easing, including curves built at runtime which can't be `Copy` like an `Easing`
+///
+/// Every setting which takes an easing accepts either an `Easing` or an `EasingCurve`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EasingCurve {
+    Easing(Easing),
+    /// Straight lines between (input, output) points ordered by input, matching the CSS
+    /// `linear()` timing function. Inputs outside of the points are extrapolated.
+    PiecewiseLinear(Arc<[(f32, f32)]>),
+    /// A user defined easing which may capture state, see `EasingCurve::function`
+    Shared(SharedEasing),
+}
+
+/// A user defined easing curve
+///
+/// Implemented for any `Fn(f32) -> f32` closure which is `Send + Sync`, so curves can be built
+/// from runtime data such as sampled points or user settings.
+pub trait EasingFunction: Send + Sync {
+    /// Maps linear progress to eased progress, both usually between 0 & 1
+    fn value(&self, x: f32) -> f32;
+}
+
+impl<F> EasingFunction for F
+where
+    F: Fn(f32) -> f32 + Send + Sync,
+{
+    fn value(&self, x: f32) -> f32 {
+        self(x)
+    }
+}
+
+/// A reference counted `EasingFunction`, which is cheap to clone
+///
+/// Shared easings are only equal to clones of themselves.
+#[derive(Clone)]
+pub struct SharedEasing(Arc<dyn EasingFunction>);
+
+impl SharedEasing {
+    pub fn new(function: impl EasingFunction + 'static) -> Self {
+        Self(Arc::new(function))
+    }
+}
+
+impl From<Arc<dyn EasingFunction>> for SharedEasing {
+    fn from(function: Arc<dyn EasingFunction>) -> Self {
+        Self(function)
+    }
+}
+
+impl From<Arc<dyn Fn(f32) -> f32 + Send + Sync>> for SharedEasing {
+    fn from(function: Arc<dyn Fn(f32) -> f32 + Send + Sync>) -> Self {
+        Self::new(move |x| function(x))
+    }
+}
+
+impl std::fmt::Debug for SharedEasing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedEasing(..)")
+    }
+}
+
+impl PartialEq for SharedEasing {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Defines where the jumps of `Easing::Steps` occur
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum StepPosition {
+    /// The first jump happens when the animation begins
+    JumpStart,
+    /// The last jump happens when the animation ends
+    #[default]
+    JumpEnd,
+    /// There's no jump at either end - the first & last steps are held
+    JumpNone,
+    /// Jumps happen both when the animation begins & ends
+    JumpBoth,
+}
+
 impl Easing {
     pub fn value(self, x: f32) -> f32 {
         let pi = std::f32::consts::PI;
@@ -623,11 +1686,144 @@ impl Easing {
                     (1.0 + Self::EaseOutBounce.value(2.0 * x - 1.0)) / 2.0
                 }
             }
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                let t = solve_bezier_parameter(x, x1, x2);
+                sample_bezier(t, y1, y2)
+            }
+            Easing::Steps(steps, position) => {
+                let steps = steps.max(1) as f32;
+                let mut step = (x * steps).floor();
+                if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+                    step += 1.;
+                }
+                let jumps = match position {
+                    StepPosition::JumpStart | StepPosition::JumpEnd => steps,
+                    StepPosition::JumpNone => f32::max(1., steps - 1.),
+                    StepPosition::JumpBoth => steps + 1.,
+                };
+                if x >= 0. && step < 0. {
+                    step = 0.;
+                }
+                if x <= 1. && step > jumps {
+                    step = jumps;
+                }
+                step / jumps
+            }
             Easing::Custom(f) => f(x),
         }
     }
 }
 
+impl EasingCurve {
+    pub fn value(&self, x: f32) -> f32 {
+        match self {
+            EasingCurve::Easing(easing) => easing.value(x),
+            EasingCurve::PiecewiseLinear(points) => match points.len() {
+                0 => x,
+                1 => points[0].1,
+                len => {
+                    let segment = points
+                        .windows(2)
+                        .position(|segment| x < segment[1].0)
+                        .unwrap_or(len - 2);
+                    let ((x1, y1), (x2, y2)) = (points[segment], points[segment + 1]);
+                    if x2 == x1 {
+                        y2
+                    } else {
+                        y1 + (x - x1) / (x2 - x1) * (y2 - y1)
+                    }
+                }
+            },
+            EasingCurve::Shared(function) => function.0.value(x),
+        }
+    }
+    /// Creates a piecewise linear easing through the given (input, output) points,
+    /// matching the CSS `linear()` timing function
+    pub fn linear(points: impl Into<Arc<[(f32, f32)]>>) -> Self {
+        EasingCurve::PiecewiseLinear(points.into())
+    }
+    /// Creates an easing from a closure or `EasingFunction`, which unlike `Easing::Custom`
+    /// may capture state
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lilt::EasingCurve;
+    ///
+    /// let exponent = 2.5;
+    /// let easing = EasingCurve::function(move |x: f32| x.powf(exponent));
+    /// assert_eq!(easing.value(1.), 1.);
+    /// ```
+    pub fn function(function: impl EasingFunction + 'static) -> Self {
+        EasingCurve::Shared(SharedEasing::new(function))
+    }
+}
+
+impl Default for EasingCurve {
+    fn default() -> Self {
+        EasingCurve::Easing(Easing::default())
+    }
+}
+
+impl From<Easing> for EasingCurve {
+    fn from(easing: Easing) -> Self {
+        EasingCurve::Easing(easing)
+    }
+}
+
+impl From<SharedEasing> for EasingCurve {
+    fn from(function: SharedEasing) -> Self {
+        EasingCurve::Shared(function)
+    }
+}
+
+/// Samples one axis of a cubic bézier curve with end points at 0 & 1
+fn sample_bezier(t: f32, p1: f32, p2: f32) -> f32 {
+    let c = 3. * p1;
+    let b = 3. * (p2 - p1) - c;
+    let a = 1. - c - b;
+    ((a * t + b) * t + c) * t
+}
+
+fn sample_bezier_derivative(t: f32, p1: f32, p2: f32) -> f32 {
+    let c = 3. * p1;
+    let b = 3. * (p2 - p1) - c;
+    let a = 1. - c - b;
+    (3. * a * t + 2. * b) * t + c
+}
+
+/// Finds the curve parameter for a given x using Newton's method, falling back to bisection
+fn solve_bezier_parameter(x: f32, x1: f32, x2: f32) -> f32 {
+    const EPSILON: f32 = 1e-6;
+    let mut t = x;
+    for _ in 0..8 {
+        let error = sample_bezier(t, x1, x2) - x;
+        if error.abs() < EPSILON {
+            return t;
+        }
+        let derivative = sample_bezier_derivative(t, x1, x2);
+        if derivative.abs() < EPSILON {
+            break;
+        }
+        t -= error / derivative;
+    }
+    let (mut low, mut high) = (0., 1.);
+    t = x.clamp(0., 1.);
+    for _ in 0..32 {
+        let sample = sample_bezier(t, x1, x2);
+        if (sample - x).abs() < EPSILON {
+            break;
+        }
+        if x > sample {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) * 0.5;
+    }
+    t
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -653,6 +1849,29 @@ mod tests {
         assert!(anim.in_progress(10000.0));
     }
 
+    #[test]
+    fn test_long_running_precision() {
+        use std::time::{Duration, Instant};
+        let start = Instant::now();
+        let mut anim: Animated<bool, Instant> = Animated::new(false)
+            .duration(1000.)
+            .easing(Easing::Linear)
+            .auto_reverse()
+            .repeat_forever();
+        anim.transition(true, start);
+
+        // Fractional milliseconds aren't truncated
+        let later = start + Duration::from_micros(1500);
+        assert!((anim.animate_bool(0f32, 1000., later) - 1.5).abs() < 1e-3);
+
+        // A month in, the phase is still exact
+        let month = Duration::from_secs(60 * 60 * 24 * 30);
+        let later = start + month + Duration::from_micros(250_500);
+        assert!((anim.animate_bool(0f32, 1000., later) - 250.5).abs() < 1e-3);
+        let later = start + month + Duration::from_micros(1_250_500);
+        assert!((anim.animate_bool(0f32, 1000., later) - 749.5).abs() < 1e-3);
+    }
+
     fn plot_easing(easing: Easing) {
         const WIDTH: usize = 80;
         const HEIGHT: usize = 40;
@@ -707,6 +1926,10 @@ mod tests {
             Easing::EaseInBounce,
             Easing::EaseOutBounce,
             Easing::EaseInOutBounce,
+            Easing::CubicBezier(0.25, 0.1, 0.25, 1.0),
+            Easing::CubicBezier(0.68, -0.6, 0.32, 1.6),
+            Easing::Steps(4, StepPosition::JumpEnd),
+            Easing::Steps(4, StepPosition::JumpBoth),
         ];
 
         for easing in &easings {
@@ -722,6 +1945,84 @@ mod tests {
         assert_eq!(custom_ease.value(1.0), 1.0);
     }
 
+    #[test]
+    fn test_cubic_bezier_easing() {
+        let linear = Easing::CubicBezier(0., 0., 1., 1.);
+        let ease = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+        for x in [0., 0.1, 0.25, 0.5, 0.9, 1.] {
+            assert!(approximately_equal(linear.value(x), x));
+        }
+        assert!(approximately_equal(ease.value(0.), 0.));
+        assert!(approximately_equal(ease.value(1.), 1.));
+        assert!(f32::abs(ease.value(0.5) - 0.8024033) < 1e-4);
+        // Control points outside of the unit range overshoot
+        let back = Easing::CubicBezier(0.68, -0.6, 0.32, 1.6);
+        assert!(back.value(0.1) < 0.);
+        assert!(back.value(0.9) > 1.);
+    }
+
+    #[test]
+    fn test_steps_easing() {
+        let end = Easing::Steps(4, StepPosition::JumpEnd);
+        assert_eq!(end.value(0.), 0.);
+        assert_eq!(end.value(0.3), 0.25);
+        assert_eq!(end.value(0.99), 0.75);
+        assert_eq!(end.value(1.), 1.);
+
+        let start = Easing::Steps(4, StepPosition::JumpStart);
+        assert_eq!(start.value(0.), 0.25);
+        assert_eq!(start.value(0.3), 0.5);
+        assert_eq!(start.value(1.), 1.);
+
+        let none = Easing::Steps(5, StepPosition::JumpNone);
+        assert_eq!(none.value(0.), 0.);
+        assert_eq!(none.value(0.5), 0.5);
+        assert_eq!(none.value(1.), 1.);
+
+        let both = Easing::Steps(3, StepPosition::JumpBoth);
+        assert_eq!(both.value(0.), 0.25);
+        assert_eq!(both.value(0.5), 0.5);
+        assert_eq!(both.value(1.), 1.);
+    }
+
+    #[test]
+    fn test_piecewise_linear_easing() {
+        let easing = EasingCurve::linear([(0., 0.), (0.5, 0.8), (1., 1.)]);
+        assert_eq!(easing.value(0.25), 0.4);
+        assert_eq!(easing.value(0.75), 0.9);
+        assert_eq!(easing.value(1.5), 1.2);
+        assert_eq!(EasingCurve::linear([]).value(0.3), 0.3);
+
+        let mut anim = Animated::new(0.).duration(1000.).easing(easing);
+        anim.transition(10., 0.);
+        assert_eq!(anim.animate_wrapped(250.), 4.);
+    }
+
+    #[test]
+    fn test_shared_easing() {
+        let points: Vec<f32> = [0., 0.1, 0.5, 1.].to_vec();
+        let sampled = EasingCurve::function(move |x: f32| {
+            let index = (x * (points.len() - 1) as f32).round() as usize;
+            points[index.min(points.len() - 1)]
+        });
+        assert_eq!(sampled.value(0.), 0.);
+        assert_eq!(sampled.value(0.4), 0.1);
+        assert_eq!(sampled.value(1.), 1.);
+        assert_eq!(sampled, sampled.clone());
+        assert_ne!(sampled, EasingCurve::function(|x| x));
+
+        let arc: Arc<dyn Fn(f32) -> f32 + Send + Sync> = Arc::new(|x| x * x);
+        let shared = SharedEasing::from(arc.clone());
+        let mut anim = Animated::new(0.).duration(1000.).easing(shared);
+        anim.transition(10.0, 0.0);
+        let cloned = anim.clone();
+        assert_eq!(cloned.animate_wrapped(500.0), 2.5);
+        assert!(format!("{:?}", cloned).contains("SharedEasing"));
+        drop((anim, cloned));
+        // Dropping every animation which uses a shared easing releases its function
+        assert_eq!(Arc::strong_count(&arc), 1);
+    }
+
     #[test]
     fn test_linear_progress() {
         let mut anim = Animated::new(0.).duration(1000.).easing(Easing::Linear);
@@ -816,6 +2117,370 @@ mod tests {
         assert_eq!(anim.animate_wrapped(1500.0), 10.0); // Completed
     }
 
+    #[test]
+    fn test_duration_configuration() {
+        use std::time::Duration;
+        let mut anim = Animated::new(0.)
+            .duration(Duration::from_secs(1))
+            .easing(Easing::Linear)
+            .delay(Duration::from_millis(500))
+            .asymmetric_duration(Duration::from_micros(2_000_500));
+        anim.transition(10.0, 0.0);
+
+        assert_eq!(anim.animate_wrapped(750.0), 2.5);
+        assert!(!anim.in_progress(1500.));
+
+        anim.transition(0.0, 2000.0);
+        assert!(anim.in_progress(4500.));
+        assert!(!anim.in_progress(4501.));
+    }
+
+    #[test]
+    fn test_pause_resume() {
+        let mut anim = Animated::new(0.).duration(1000.).easing(Easing::Linear);
+        anim.transition(10.0, 0.0);
+        anim.pause(250.0);
+        assert!(anim.is_paused());
+        assert_eq!(anim.animate_wrapped(250.0), 2.5);
+        assert_eq!(anim.animate_wrapped(5000.0), 2.5);
+
+        anim.resume(5000.0);
+        assert!(!anim.is_paused());
+        assert_eq!(anim.animate_wrapped(5500.0), 7.5);
+        assert!(anim.in_progress(5749.0));
+        assert!(!anim.in_progress(5750.0));
+    }
+
+    #[test]
+    fn test_speed() {
+        let mut anim = Animated::new(0.).duration(1000.).easing(Easing::Linear);
+        anim.transition(10.0, 0.0);
+        anim.set_speed(0.5, 200.0);
+        assert_eq!(anim.speed(), 0.5);
+        assert_eq!(anim.animate_wrapped(600.0), 4.0);
+        assert!(!anim.in_progress(1800.0));
+
+        // Reversing a completed animation plays back from its end
+        anim.set_speed(-2.0, 3000.0);
+        assert_eq!(anim.animate_wrapped(3000.0), 10.0);
+        assert_eq!(anim.animate_wrapped(3250.0), 5.0);
+        assert!(anim.in_progress(3499.0));
+        assert!(!anim.in_progress(3500.0));
+        assert_eq!(anim.animate_wrapped(4000.0), 0.0);
+    }
+
+    #[test]
+    fn test_transition_while_reversed() {
+        let mut anim = Animated::new(0.).duration(1000.).easing(Easing::Linear);
+        anim.set_speed(-1.0, 0.0);
+        // New transitions play forwards at the reversed speed
+        anim.transition(10.0, 0.0);
+        assert_eq!(anim.speed(), 1.0);
+        assert!(anim.in_progress(0.0));
+        assert_eq!(anim.animate_wrapped(500.0), 5.0);
+
+        // Interrupting a reversed transition continues from where it had reversed to
+        anim.set_speed(-2.0, 500.0);
+        assert_eq!(anim.animate_wrapped(600.0), 3.0);
+        anim.transition(20.0, 600.0);
+        assert_eq!(anim.speed(), 2.0);
+        assert_eq!(anim.animate_wrapped(600.0), 3.0);
+        assert!(!anim.in_progress(1100.0));
+        assert_eq!(anim.animate_wrapped(1100.0), 20.0);
+        assert_eq!(anim.total_duration(), 500.0);
+
+        anim.pause(1100.0);
+        assert_eq!(anim.total_duration(), f32::INFINITY);
+
+        // Springs keep moving in the direction they were reversing in
+        let mut anim = Animated::new(0.).spring(Spring::smooth());
+        anim.transition(10.0, 0.0);
+        anim.set_speed(-1.0, 200.0);
+        let reversed = anim.animate_wrapped(300.0);
+        anim.transition(20.0, 300.0);
+        assert!((anim.animate_wrapped(300.0) - reversed).abs() < 1e-4);
+        assert!(anim.animate_wrapped(301.0) < reversed);
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut anim = Animated::new(0.)
+            .duration(1000.)
+            .easing(Easing::EaseIn)
+            .delay(100.);
+        anim.transition(10.0, 0.0);
+        assert_eq!(anim.progress_at(600.0), 0.5);
+
+        anim.seek_to_progress(0.25, 2000.0);
+        assert_eq!(anim.progress_at(2000.0), 0.25);
+        assert_eq!(anim.linear_progress(2000.0), 2.5);
+        assert_eq!(
+            anim.eased_progress(2000.0),
+            Easing::EaseIn.value(0.25) * 10.
+        );
+        assert_eq!(anim.eased_progress_at(2000.0), Easing::EaseIn.value(0.25));
+        assert_eq!(anim.progress_at(2250.0), 0.5);
+        assert!(!anim.in_progress(2750.0));
+        assert_eq!(anim.progress_at(5000.0), 1.0);
+        assert_eq!(anim.eased_progress_at(5000.0), 1.0);
+
+        // Seeking has no effect before the first transition
+        let mut anim = Animated::new(5.).duration(1000.);
+        anim.seek_to_progress(0.5, 0.0);
+        assert_eq!(anim.animate_wrapped(0.0), 5.0);
+        assert!(!anim.in_progress(0.0));
+    }
+
+    #[test]
+    fn test_seek_additive() {
+        let mut anim = Animated::new(0.)
+            .duration(1000.)
+            .easing(Easing::Linear)
+            .interruption(Interruption::Additive);
+        anim.transition(10.0, 0.0);
+        anim.transition(20.0, 400.0);
+
+        // Interrupted transitions are moved along with the current one
+        anim.seek_to_progress(1.0, 600.0);
+        assert_eq!(anim.animate_wrapped(600.0), 20.0);
+        assert!(!anim.in_progress(600.0));
+        anim.seek_to_progress(0.0, 700.0);
+        assert_eq!(anim.animate_wrapped(700.0), 0.0);
+        anim.seek_to_progress(0.5, 800.0);
+        assert_eq!(anim.animate_wrapped(800.0), 10.0);
+    }
+
+    #[test]
+    fn test_hold_at_progress() {
+        let mut anim = Animated::new(0.)
+            .duration(500.)
+            .easing(Easing::Linear)
+            .auto_reverse()
+            .repeat_forever();
+        anim.transition(10.0, 0.0);
+        for (unit, value) in [(0.1, 2.0), (0.75, 5.0), (0.5, 10.0)] {
+            anim.hold_at_progress(unit, 100.0);
+            assert_eq!(anim.progress_at(100.0), unit);
+            assert_eq!(anim.animate_wrapped(1000.0), value);
+        }
+        anim.resume(1000.0);
+        assert_eq!(anim.animate_wrapped(1250.0), 5.0);
+    }
+
+    #[test]
+    fn test_events() {
+        use AnimationEvent::*;
+        let mut anim = Animated::new(0.)
+            .duration(100.)
+            .delay(50.)
+            .auto_reverse()
+            .repeat(1);
+        assert!(anim.events_between(0.0, 1000.0).is_empty());
+
+        anim.transition(10.0, 0.0);
+        assert!(anim.events_between(0.0, 50.0).is_empty());
+        assert_eq!(anim.events_between(0.0, 60.0), vec![Started]);
+        assert_eq!(anim.events_between(60.0, 150.0), vec![Reversed(1)]);
+        assert!(anim.events_between(150.0, 160.0).is_empty());
+        assert_eq!(
+            anim.events_between(160.0, 250.0),
+            vec![RepetitionCompleted(1)]
+        );
+        assert_eq!(anim.events_between(250.0, 350.0), vec![Completed]);
+        assert_eq!(
+            anim.events_between(-10.0, 1000.0),
+            vec![Started, Reversed(1), RepetitionCompleted(1), Completed]
+        );
+        assert!(anim.events_between(350.0, 1000.0).is_empty());
+    }
+
+    #[test]
+    fn test_events_without_duration() {
+        use AnimationEvent::*;
+        let mut anim = Animated::new(0.).duration(0.).delay(50.);
+        anim.transition(10.0, 0.0);
+        assert!(anim.events_between(0.0, 50.0).is_empty());
+        assert_eq!(anim.events_between(40.0, 60.0), vec![Started, Completed]);
+        assert!(anim.events_between(60.0, 100.0).is_empty());
+
+        // Instantaneous transitions replace the current transition without any events
+        let mut anim = Animated::new(0.).duration(100.);
+        anim.transition(10.0, 0.0);
+        anim.transition_instantaneous(20.0, 50.0);
+        assert!(anim.events_between(50.0, 200.0).is_empty());
+        assert!(!anim.in_progress(50.0));
+        assert_eq!(anim.animate_wrapped(50.0), 20.0);
+    }
+
+    #[test]
+    fn test_repeat_forever_events() {
+        let mut anim = Animated::new(0.).duration(100.).repeat_forever();
+        anim.transition(10.0, 0.0);
+        assert_eq!(
+            anim.events_between(50.0, 350.0),
+            vec![AnimationEvent::RepetitionCompleted(3)]
+        );
+
+        // Long gaps between frames produce a single event counting every repetition
+        let mut anim = Animated::new(0.).duration(10.).repeat_forever();
+        anim.transition(10.0, 0.0);
+        assert_eq!(
+            anim.events_between(5.0, 1e8),
+            vec![AnimationEvent::RepetitionCompleted(10_000_000)]
+        );
+        let mut anim = Animated::new(0.)
+            .duration(10.)
+            .auto_reverse()
+            .repeat_forever();
+        anim.transition(10.0, 0.0);
+        assert_eq!(
+            anim.events_between(15.0, 1e8),
+            vec![
+                AnimationEvent::RepetitionCompleted(5_000_000),
+                AnimationEvent::Reversed(4_999_999)
+            ]
+        );
+
+        // Reversing playback completes once the start is reached again
+        let mut anim = Animated::new(0.).duration(100.);
+        anim.transition(10.0, 0.0);
+        anim.set_speed(-1.0, 50.0);
+        assert_eq!(
+            anim.events_between(50.0, 100.0),
+            vec![AnimationEvent::Completed]
+        );
+    }
+
+    #[test]
+    fn test_next_change() {
+        use std::time::Instant;
+        let ms = |ms: u64| Duration::from_millis(ms);
+        let start = Instant::now();
+        let mut anim: Animated<f32, Instant> = Animated::new(0.)
+            .duration(400.)
+            .easing(Easing::Steps(4, StepPosition::JumpEnd))
+            .delay(100.);
+        assert_eq!(anim.next_change_at(start), NextChange::Idle);
+        assert_eq!(anim.settles_at(), None);
+
+        anim.transition(10., start);
+        assert_eq!(anim.settles_at(), Some(start + ms(500)));
+        assert_eq!(anim.next_change_at(start), NextChange::At(start + ms(100)));
+        assert_eq!(
+            anim.next_change_at(start + ms(150)),
+            NextChange::At(start + ms(200))
+        );
+        assert_eq!(
+            anim.next_change_at(start + ms(450)),
+            NextChange::At(start + ms(500))
+        );
+        assert_eq!(anim.next_change_at(start + ms(500)), NextChange::Idle);
+
+        anim.set_speed(2., start + ms(100));
+        assert_eq!(anim.settles_at(), Some(start + ms(300)));
+        assert_eq!(
+            anim.next_change_at(start + ms(100)),
+            NextChange::At(start + ms(150))
+        );
+        anim.pause(start + ms(100));
+        assert_eq!(anim.next_change_at(start + ms(100)), NextChange::Idle);
+        assert_eq!(anim.settles_at(), None);
+
+        let mut anim: Animated<f32, Instant> = Animated::new(0.).duration(400.);
+        anim.transition(10., start);
+        assert_eq!(anim.next_change_at(start), NextChange::Continuous);
+    }
+
+    #[test]
+    fn test_transition_with() {
+        let mut anim = Animated::new(0.)
+            .duration(1000.)
+            .easing(Easing::Linear)
+            .asymmetric_duration(500.);
+        anim.transition_with(
+            10.0,
+            0.0,
+            Transition::new()
+                .duration(200.)
+                .easing(Easing::EaseIn)
+                .delay(100.),
+        );
+        assert_eq!(anim.animate_wrapped(100.0), 0.0);
+        assert_eq!(anim.animate_wrapped(200.0), Easing::EaseIn.value(0.5) * 10.);
+        assert!(!anim.in_progress(300.0));
+
+        // Overrides apply in either direction
+        anim.transition_with(0.0, 1000.0, Transition::new().duration(200.));
+        assert!(!anim.in_progress(1200.0));
+
+        // The configured settings are restored for the next transition
+        anim.transition(10.0, 2000.0);
+        assert_eq!(anim.animate_wrapped(2500.0), 5.0);
+        anim.transition(0.0, 3000.0);
+        assert_eq!(anim.animate_wrapped(3250.0), 5.0);
+
+        // The most recently specified timing wins
+        let spring = Spring::smooth();
+        let settled = spring.settling_duration_ms(0.);
+        anim.transition_with(
+            10.0,
+            4000.0,
+            Transition::new().spring(spring).duration(200.),
+        );
+        assert_eq!(anim.animate_wrapped(4100.0), 5.0);
+        anim.transition_with(0.0, 5000.0, Transition::new().duration(200.).spring(spring));
+        assert!(anim.in_progress(5000.0 + settled - 1.0));
+        assert!(!anim.in_progress(5000.0 + settled + 1.0));
+    }
+
+    #[test]
+    fn test_transition_with_interruption() {
+        let mut anim = Animated::new(0.).duration(1000.).easing(Easing::Linear);
+        anim.transition_with(10.0, 0.0, Transition::new().duration(200.));
+        // The interrupted transition is sampled using its overridden duration
+        anim.transition(20.0, 100.0);
+        assert_eq!(anim.animate_wrapped(100.0), 5.0);
+        assert_eq!(anim.animate_wrapped(600.0), 12.5);
+    }
+
+    #[test]
+    fn test_transition_rules() {
+        let mut anim = Animated::new(0.)
+            .duration(100.)
+            .easing(Easing::Linear)
+            .transition_rule(None, Some(2.), Transition::new().duration(200.))
+            .transition_rule(None, Some(3.), Transition::new().duration(250.))
+            .transition_rule(Some(1.), None, Transition::new().duration(300.))
+            .transition_rule(Some(1.), Some(2.), Transition::new().duration(400.))
+            .transition_rule(None, None, Transition::new().delay(50.))
+            .transition_rule(None, None, Transition::new().delay(10.));
+
+        let mut settles = |value, at| {
+            anim.transition(value, at);
+            anim.total_duration()
+        };
+        // Only the wildcard rule added last matches
+        assert_eq!(settles(1., 0.), 110.);
+        // The exact pair is preferred over either wildcard
+        assert_eq!(settles(2., 1000.), 400.);
+        assert_eq!(settles(1., 2000.), 110.);
+        assert_eq!(settles(2., 3000.), 400.);
+        assert_eq!(settles(0., 4000.), 110.);
+        // A rule for the destination matches from any origin
+        assert_eq!(settles(2., 5000.), 200.);
+        assert_eq!(settles(1., 6000.), 110.);
+        // A rule for the origin matches towards any destination
+        assert_eq!(settles(0., 7000.), 300.);
+        // A rule for the destination is preferred over one for the origin,
+        // even though the origin rule was added later
+        assert_eq!(settles(1., 8000.), 110.);
+        assert_eq!(settles(3., 9000.), 250.);
+
+        // Explicit overrides take precedence over rules
+        anim.transition_with(1., 10000., Transition::new().duration(20.));
+        assert_eq!(anim.total_duration(), 20.);
+    }
+
     #[test]
     fn test_interruption() {
         let mut anim = Animated::new(0.).duration(1000.).easing(Easing::Linear);
@@ -1169,6 +2834,101 @@ mod tests {
         assert_eq!(anim.animate_bool(0., 10., 3000.), 10.);
     }
 
+    #[test]
+    fn test_spring() {
+        let mut anim = Animated::new(0.).spring(Spring::smooth());
+        anim.transition(10.0, 0.0);
+        let settled = Spring::smooth().settling_duration_ms(0.);
+
+        assert_eq!(anim.animate_wrapped(0.0), 0.0);
+        assert!(anim.animate_wrapped(100.0) > 0.0);
+        assert!(anim.in_progress(settled - 1.));
+        assert!(!anim.in_progress(settled));
+        assert_eq!(anim.animate_wrapped(settled), 10.0);
+    }
+
+    #[test]
+    fn test_spring_interruption_preserves_velocity() {
+        let mut anim = Animated::new(0.).spring(Spring::snappy());
+        anim.transition(10.0, 0.0);
+        let before = anim.animate_wrapped(99.0);
+        let at = anim.animate_wrapped(100.0);
+
+        // Retargeting further in the same direction keeps the motion continuous
+        anim.transition(20.0, 100.0);
+        let after = anim.animate_wrapped(101.0);
+        assert!(approximately_equal(anim.animate_wrapped(100.0), at));
+        assert!(f32::abs((after - at) - (at - before)) < 1e-2);
+    }
+
+    #[test]
+    fn test_asymmetric_spring() {
+        let mut anim = Animated::new(0.)
+            .duration(1000.)
+            .easing(Easing::Linear)
+            .asymmetric_spring(Spring::bouncy());
+        anim.transition(10.0, 0.0);
+        assert_eq!(anim.animate_wrapped(500.0), 5.0);
+        anim.transition(0.0, 1000.0);
+        let settled = Spring::bouncy().settling_duration_ms(0.);
+        assert!(anim.in_progress(1000. + settled - 1.));
+        assert_eq!(anim.animate_wrapped(1000. + settled), 0.0);
+    }
+
+    #[test]
+    fn test_additive_interruption() {
+        let mut anim = Animated::new(0.)
+            .duration(1000.)
+            .easing(Easing::EaseInOut)
+            .interruption(Interruption::Additive);
+        anim.transition(10.0, 0.0);
+        let before = anim.animate_wrapped(499.0);
+        let at = anim.animate_wrapped(500.0);
+
+        anim.transition(20.0, 500.0);
+        let after = anim.animate_wrapped(501.0);
+        assert!(approximately_equal(anim.animate_wrapped(500.0), at));
+        // Velocity is preserved through the interruption
+        assert!(f32::abs((after - at) - (at - before)) < 1e-3);
+        // The interrupted animation completes before the new one
+        assert!(anim.in_progress(1000.0));
+        assert!(anim.in_progress(1499.0));
+        assert_eq!(anim.animate_wrapped(1500.0), 20.0);
+        assert!(!anim.in_progress(1500.0));
+    }
+
+    #[test]
+    fn test_additive_interruption_reversal() {
+        let mut anim = Animated::new(false)
+            .duration(1000.)
+            .easing(Easing::EaseInOut)
+            .asymmetric_duration(500.)
+            .interruption(Interruption::Additive);
+        anim.transition(true, 0.0);
+        let at = anim.animate_bool(0., 10., 500.0);
+        anim.transition(false, 500.0);
+        assert!(approximately_equal(anim.animate_bool(0., 10., 500.0), at));
+        // Momentum from the forward animation carries on briefly
+        assert!(anim.animate_bool(0., 10., 550.0) > at);
+        assert_eq!(anim.animate_bool(0., 10., 1000.0), 0.0);
+        assert!(!anim.in_progress(1000.0));
+
+        anim.transition(true, 2000.0);
+        assert_eq!(anim.animate_bool(0., 10., 2500.0), 5.0);
+    }
+
+    #[test]
+    fn test_additive_interruption_repeating() {
+        let mut anim = Animated::new(0.)
+            .duration(1000.)
+            .easing(Easing::Linear)
+            .repeat_forever()
+            .interruption(Interruption::Additive);
+        anim.transition(10.0, 0.0);
+        anim.transition(20.0, 500.0);
+        assert_eq!(anim.animate_wrapped(1000.0), 12.5);
+    }
+
     impl AnimationTime for f32 {
         fn elapsed_since(self, time: Self) -> f32 {
             self - time
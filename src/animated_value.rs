@@ -0,0 +1,336 @@
+use crate::animated::{Animated, AnimationEvent, EasingCurve, NextChange, Transition};
+use crate::spring::Spring;
+use crate::traits::{AnimationTime, Interpolable, IntoMilliseconds};
+use std::ops::Add;
+use std::time::Duration;
+
+/// Wraps an interpolable value to enable transitions, without mapping it to a float axis
+///
+/// `Animated` requires a `FloatRepresentable` value & reconstructs interrupted positions by
+/// re-interpolating between mapped states. `AnimatedValue` instead stores the value it's
+/// animating from & to, so values without a meaningful ordering such as colors, rects or
+/// transforms can be animated directly. An interrupted transition continues from the value
+/// at the time it was interrupted, as with `Interruption::Restart` - additive interruption
+/// isn't supported, since it requires offsetting one value by the difference of two others.
+///
+/// Without a way to compare the directions of arbitrary values, the velocity of an
+/// interrupted spring is only carried over when it's reversed back towards its origin.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{color::Color, AnimatedValue, Easing};
+/// use std::time::Instant;
+///
+/// let mut background: AnimatedValue<Color, Instant> = AnimatedValue::new(Color::WHITE)
+///     .duration(300.)
+///     .easing(Easing::EaseOut);
+///
+/// let now = Instant::now();
+/// background.transition(Color::from_rgb8(96, 162, 241), now);
+/// let current = background.animate(now);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimatedValue<I, Time>
+where
+    I: Interpolable + Clone + PartialEq,
+    Time: AnimationTime,
+{
+    /// Only the timing of the float axis is used, which always animates from 0 to 1
+    timing: Animated<f32, Time>,
+    origin: I,
+    destination: I,
+}
+
+impl<I, Time> AnimatedValue<I, Time>
+where
+    I: Interpolable + Clone + PartialEq,
+    Time: AnimationTime,
+{
+    /// Creates an animated value with a default animation
+    pub fn new(value: I) -> Self {
+        Self {
+            timing: Animated::new(0.),
+            origin: value.clone(),
+            destination: value,
+        }
+    }
+    /// Specifies the duration of the animation as a `Duration` or a number of milliseconds
+    /// Replaces any previously specified spring
+    pub fn duration(mut self, duration: impl IntoMilliseconds) -> Self {
+        self.timing = self.timing.duration(duration);
+        self
+    }
+    /// Specifies the easing with which to animate transitions
+    /// Replaces any previously specified spring
+    pub fn easing(mut self, easing: impl Into<EasingCurve>) -> Self {
+        self.timing = self.timing.easing(easing);
+        self
+    }
+    /// Animates transitions using a spring instead of a duration & easing
+    /// Springs reversed back towards their origin keep their momentum
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.timing = self.timing.spring(spring);
+        self
+    }
+    /// Delays the animation by a `Duration` or a number of milliseconds
+    pub fn delay(mut self, delay: impl IntoMilliseconds) -> Self {
+        self.timing = self.timing.delay(delay);
+        self
+    }
+    /// Repeats animations the specified number of times
+    /// Passing a repetition count of 1 plays the animation twice in total
+    pub fn repeat(mut self, count: u32) -> Self {
+        self.timing = self.timing.repeat(count);
+        self
+    }
+    /// Repeats transitions forever
+    pub fn repeat_forever(mut self) -> Self {
+        self.timing = self.timing.repeat_forever();
+        self
+    }
+    /// Automatically play repetitions in reverse after they complete
+    pub fn auto_reverse(mut self) -> Self {
+        self.timing = self.timing.auto_reverse();
+        self
+    }
+    /// Begins a transition as soon as the animation is created
+    pub fn auto_start(mut self, new_value: I, at: Time) -> Self {
+        self.transition(new_value, at);
+        self
+    }
+    /// The value being transitioned to
+    pub fn value(&self) -> &I {
+        &self.destination
+    }
+    /// Begins a transition from the current value to a new value
+    /// Transitions to the value which is already being transitioned to are ignored
+    pub fn transition(&mut self, new_value: I, at: Time) {
+        self.transition_internal(new_value, at, false, None);
+    }
+    /// Begins a transition using the given settings in place of those of the animation,
+    /// for this transition only
+    pub fn transition_with(&mut self, new_value: I, at: Time, overrides: Transition) {
+        self.transition_internal(new_value, at, false, Some(&overrides));
+    }
+    /// Instantaneously completes a transition to a new value, without emitting any events
+    pub fn transition_instantaneous(&mut self, new_value: I, at: Time) {
+        self.transition_internal(new_value, at, true, None);
+    }
+    fn transition_internal(
+        &mut self,
+        new_value: I,
+        at: Time,
+        instantaneous: bool,
+        overrides: Option<&Transition>,
+    ) {
+        if self.destination != new_value {
+            // Reversing covers the progress made so far in the opposite direction, so the
+            // velocity is scaled by the ratio of the spans. Other directions can't be related.
+            let progress = self.timing.eased_progress_at(at);
+            let span_ratio = if new_value == self.origin && progress != 0. {
+                -1. / progress
+            } else {
+                0.
+            };
+            self.origin = if instantaneous {
+                new_value.clone()
+            } else {
+                self.animate(at)
+            };
+            self.destination = new_value;
+            self.timing
+                .restart(at, instantaneous, overrides, span_ratio);
+        }
+    }
+    /// The interpolated value, given the current time
+    pub fn animate(&self, time: Time) -> I {
+        self.origin.interpolated(
+            self.destination.clone(),
+            self.timing.eased_progress_at(time),
+        )
+    }
+    /// Returns whether the animation is complete, given the current time
+    pub fn in_progress(&self, time: Time) -> bool {
+        self.timing.in_progress(time)
+    }
+    /// Milestones reached by the current transition after `previous` & up to `now`, in order
+    pub fn events_between(&self, previous: Time, now: Time) -> Vec<AnimationEvent> {
+        self.timing.events_between(previous, now)
+    }
+    /// Freezes the animation at its current progress until it's resumed
+    pub fn pause(&mut self, at: Time) {
+        self.timing.pause(at);
+    }
+    /// Continues a paused animation from where it left off
+    pub fn resume(&mut self, at: Time) {
+        self.timing.resume(at);
+    }
+    /// Returns whether the animation is paused
+    pub fn is_paused(&self) -> bool {
+        self.timing.is_paused()
+    }
+    /// The rate at which the animation plays, where 1 is normal speed
+    pub fn speed(&self) -> f32 {
+        self.timing.speed()
+    }
+    /// Plays the animation at a multiple of its normal speed from the given time onwards
+    /// Negative rates play the current transition in reverse, back towards its start.
+    /// Later transitions play forwards at the same speed.
+    pub fn set_speed(&mut self, rate: f32, at: Time) {
+        self.timing.set_speed(rate, at);
+    }
+    /// Moves the current transition to the given unit progress through its duration
+    /// Before the first transition the value is already at rest, so seeking has no effect.
+    pub fn seek_to_progress(&mut self, unit_progress: f32, at: Time) {
+        self.timing.seek_to_progress(unit_progress, at);
+    }
+    /// Holds the current transition at the given unit progress until it's resumed
+    pub fn hold_at_progress(&mut self, unit_progress: f32, at: Time) {
+        self.timing.hold_at_progress(unit_progress, at);
+    }
+    /// Unit progress through the duration of the current transition, excluding delay
+    pub fn progress_at(&self, time: Time) -> f32 {
+        self.timing.progress_at(time)
+    }
+    /// Unit progress through the current transition with its easing applied
+    pub fn eased_progress_at(&self, time: Time) -> f32 {
+        self.timing.eased_progress_at(time)
+    }
+}
+
+impl<I, Time> AnimatedValue<I, Time>
+where
+    I: Interpolable + Clone + PartialEq,
+    Time: AnimationTime + Add<Duration, Output = Time>,
+{
+    /// When the animated value will next change, given the current time
+    pub fn next_change_at(&self, now: Time) -> NextChange<Time> {
+        self.timing.next_change_at(now)
+    }
+    /// When the current transition will complete at its current speed
+    /// Returns `None` if there's no transition, it repeats forever, or it's paused
+    pub fn settles_at(&self) -> Option<Time> {
+        self.timing.settles_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::angle::Angle;
+    use crate::animated::Easing;
+    use crate::color::Color;
+    use crate::transform::Transform2D;
+
+    #[test]
+    fn test_transition() {
+        let mut rect = AnimatedValue::new((0., 0., 10., 10.))
+            .duration(1000.)
+            .easing(Easing::Linear);
+        assert_eq!(rect.animate(0.), (0., 0., 10., 10.));
+
+        rect.transition((100., 50., 20., 10.), 0.);
+        assert_eq!(rect.value(), &(100., 50., 20., 10.));
+        assert_eq!(rect.animate(500.), (50., 25., 15., 10.));
+        assert!(rect.in_progress(999.));
+        assert!(!rect.in_progress(1000.));
+        assert_eq!(rect.animate(1000.), (100., 50., 20., 10.));
+
+        rect.transition_instantaneous((0., 0., 10., 10.), 2000.);
+        assert_eq!(rect.animate(2000.), (0., 0., 10., 10.));
+        assert!(!rect.in_progress(2000.));
+    }
+
+    fn approximately_equal(a: Color, b: Color) -> bool {
+        let (a, b) = (a.to_linear_rgb(), b.to_linear_rgb());
+        (a.0 - b.0).abs() < 1e-5 && (a.1 - b.1).abs() < 1e-5 && (a.2 - b.2).abs() < 1e-5
+    }
+
+    #[test]
+    fn test_interruption() {
+        let mut color = AnimatedValue::new(Color::BLACK)
+            .duration(1000.)
+            .easing(Easing::Linear);
+        color.transition(Color::WHITE, 0.);
+        let interrupted = color.animate(500.);
+
+        // Continues from the interrupted color rather than re-interpolating between targets
+        color.transition(Color::rgb(1., 0., 0.), 500.);
+        assert!(approximately_equal(color.animate(500.), interrupted));
+        assert!(approximately_equal(
+            color.animate(1000.),
+            interrupted.interpolated(Color::rgb(1., 0., 0.), 0.5)
+        ));
+        assert!(approximately_equal(
+            color.animate(1500.),
+            Color::rgb(1., 0., 0.)
+        ));
+
+        // Transitions to the current destination don't restart
+        color.transition(Color::rgb(1., 0., 0.), 1500.);
+        assert!(!color.in_progress(1500.));
+    }
+
+    #[test]
+    fn test_spring_reversal() {
+        let spring = Spring::smooth();
+        let mut value = AnimatedValue::new(0.).spring(spring);
+        let mut float = Animated::new(0.).spring(spring);
+        value.transition(100., 0.);
+        float.transition(100., 0.);
+
+        // Momentum carries on past the interruption, matching `Animated`
+        let interrupted = value.animate(200.);
+        value.transition(0., 200.);
+        float.transition(0., 200.);
+        assert!(value.animate(201.) > interrupted);
+        for time in [201., 250., 400., 800.] {
+            assert!((value.animate(time) - float.animate_wrapped(time)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_scheduling() {
+        use crate::animated::StepPosition;
+        use std::time::Instant;
+        let ms = |ms: u64| Duration::from_millis(ms);
+        let start = Instant::now();
+        let mut value: AnimatedValue<f32, Instant> = AnimatedValue::new(0.)
+            .duration(400.)
+            .easing(Easing::Steps(4, StepPosition::JumpEnd))
+            .delay(100.);
+        assert_eq!(value.settles_at(), None);
+
+        value.transition(10., start);
+        assert_eq!(value.settles_at(), Some(start + ms(500)));
+        assert_eq!(value.next_change_at(start), NextChange::At(start + ms(100)));
+        assert_eq!(
+            value.next_change_at(start + ms(150)),
+            NextChange::At(start + ms(200))
+        );
+
+        value.hold_at_progress(0.5, start + ms(150));
+        assert!(value.is_paused());
+        assert_eq!(value.animate(start + ms(1000)), 5.);
+        assert_eq!(value.next_change_at(start + ms(1000)), NextChange::Idle);
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut transform = AnimatedValue::new(Transform2D::identity())
+            .duration(1000.)
+            .easing(Easing::Linear);
+        transform.transition_with(
+            Transform2D::rotation(Angle::degrees(90.)),
+            0.,
+            Transition::new().duration(500.),
+        );
+        let (x, y) = transform.animate(250.).transform_point(1., 0.);
+        let expected = Angle::degrees(45.).as_radians();
+        assert!((x - expected.cos()).abs() < 1e-5);
+        assert!((y - expected.sin()).abs() < 1e-5);
+        assert!(!transform.in_progress(500.));
+    }
+}
@@ -0,0 +1,380 @@
+//! Colors which can be interpolated in perceptual color spaces.
+//!
+//! Interpolating the components of sRGB colors directly produces dark & muddy midpoints.
+//! `Color` stores sRGB components but is interpolated in the `ColorSpace` of its
+//! destination, which is `ColorSpace::Oklab` by default.
+//!
+//! # Example
+//!
+//! ```rust
+//! use lilt::color::{Color, ColorSpace};
+//! use lilt::Animated;
+//! use std::time::Instant;
+//!
+//! let red = Color::from_rgb8(235, 75, 67);
+//! let green = Color::from_rgb8(96, 189, 93).space(ColorSpace::Oklch);
+//!
+//! let now = Instant::now();
+//! let mut toggle: Animated<bool, Instant> = Animated::new(false);
+//! toggle.transition(true, now);
+//! let [r, g, b, a] = toggle.animate_bool(red, green, now).to_rgba8();
+//! ```
+
+use crate::traits::Interpolable;
+
+/// An sRGB color with alpha, interpolated in a configurable color space
+///
+/// Components are nominally within 0...1, though out of gamut values are preserved
+/// until they're converted to bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+    space: ColorSpace,
+}
+
+/// The color space in which a `Color` is interpolated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorSpace {
+    /// Gamma encoded sRGB components, as most software interpolates colors
+    Srgb,
+    /// Physically linear light, which mixes like light does
+    LinearSrgb,
+    /// Perceptually uniform lightness & opponent components
+    #[default]
+    Oklab,
+    /// The polar form of OKLab, which preserves saturation by interpolating hue
+    Oklch,
+    /// Hue, saturation & lightness of sRGB
+    Hsl,
+}
+
+/// Below this chroma or saturation a color has no meaningful hue
+const ACHROMATIC_THRESHOLD: f32 = 1e-4;
+
+impl Color {
+    pub const BLACK: Self = Self::rgb(0., 0., 0.);
+    pub const WHITE: Self = Self::rgb(1., 1., 1.);
+    pub const TRANSPARENT: Self = Self::rgba(0., 0., 0., 0.);
+
+    /// Creates an opaque color from sRGB components
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::rgba(r, g, b, 1.)
+    }
+    /// Creates a color from sRGB components & alpha
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            a,
+            space: ColorSpace::Oklab,
+        }
+    }
+    /// Creates an opaque color from 8-bit sRGB components
+    pub fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self::from_rgba8(r, g, b, 255)
+    }
+    /// Creates a color from 8-bit sRGB components & alpha
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::rgba(
+            r as f32 / 255.,
+            g as f32 / 255.,
+            b as f32 / 255.,
+            a as f32 / 255.,
+        )
+    }
+    /// Creates a color from linear sRGB components & alpha
+    pub fn from_linear_rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::rgba(encode_srgb(r), encode_srgb(g), encode_srgb(b), a)
+    }
+    /// Creates a color from OKLab lightness, a & b components & alpha
+    pub fn from_oklab(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+        Self::from_linear_rgba(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            alpha,
+        )
+    }
+    /// Creates a color from OKLCH lightness, chroma, hue in degrees & alpha
+    pub fn from_oklch(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        let (sin, cos) = h.to_radians().sin_cos();
+        Self::from_oklab(l, c * cos, c * sin, alpha)
+    }
+    /// Creates a color from a hue in degrees, saturation, lightness & alpha
+    pub fn from_hsl(h: f32, s: f32, l: f32, alpha: f32) -> Self {
+        let chroma = s * f32::min(l, 1. - l);
+        let channel = |n: f32| {
+            let k = (n + h / 30.).rem_euclid(12.);
+            l - chroma * (k - 3.).min(9. - k).clamp(-1., 1.)
+        };
+        Self::rgba(channel(0.), channel(8.), channel(4.), alpha)
+    }
+    /// Sets the color space used when transitioning to this color
+    pub fn space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+    /// The color space used when transitioning to this color
+    pub fn color_space(&self) -> ColorSpace {
+        self.space
+    }
+    /// The 8-bit sRGB components & alpha, clamped to the sRGB gamut
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(|c| (c.clamp(0., 1.) * 255.).round() as u8)
+    }
+    /// The linear sRGB components
+    pub fn to_linear_rgb(&self) -> (f32, f32, f32) {
+        (
+            decode_srgb(self.r),
+            decode_srgb(self.g),
+            decode_srgb(self.b),
+        )
+    }
+    /// The OKLab lightness, a & b components
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_linear_rgb();
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+    /// The OKLCH lightness, chroma & hue in degrees
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let (l, a, b) = self.to_oklab();
+        (l, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.))
+    }
+    /// The hue in degrees, saturation & lightness
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (r, g, b) = (self.r, self.g, self.b);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.;
+        let delta = max - min;
+        if delta == 0. {
+            return (0., 0., l);
+        }
+        let s = delta / (1. - (2. * l - 1.).abs());
+        let h = if max == r {
+            (g - b) / delta
+        } else if max == g {
+            (b - r) / delta + 2.
+        } else {
+            (r - g) / delta + 4.
+        };
+        ((h * 60.).rem_euclid(360.), s, l)
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::TRANSPARENT
+    }
+}
+
+/// Colors are interpolated in the color space of the destination, using premultiplied alpha
+/// so that transparent colors don't darken a transition. Hues take the shortest path around
+/// the color wheel, & achromatic colors take on the hue of the color they're mixed with.
+impl Interpolable for Color {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        let alpha = self.a.interpolated(other.a, ratio);
+        let mix = |from: (f32, f32, f32), to: (f32, f32, f32), hue: Option<usize>| {
+            let (from, to) = ([from.0, from.1, from.2], [to.0, to.1, to.2]);
+            let mut mixed = [0.; 3];
+            for (index, component) in mixed.iter_mut().enumerate() {
+                *component = if hue == Some(index) {
+                    interpolate_hue(from[index], to[index], ratio)
+                } else if alpha == 0. {
+                    from[index].interpolated(to[index], ratio)
+                } else {
+                    (from[index] * self.a).interpolated(to[index] * other.a, ratio) / alpha
+                };
+            }
+            (mixed[0], mixed[1], mixed[2])
+        };
+        let color = match other.space {
+            ColorSpace::Srgb => {
+                let (r, g, b) = mix((self.r, self.g, self.b), (other.r, other.g, other.b), None);
+                Self::rgba(r, g, b, alpha)
+            }
+            ColorSpace::LinearSrgb => {
+                let (r, g, b) = mix(self.to_linear_rgb(), other.to_linear_rgb(), None);
+                Self::from_linear_rgba(r, g, b, alpha)
+            }
+            ColorSpace::Oklab => {
+                let (l, a, b) = mix(self.to_oklab(), other.to_oklab(), None);
+                Self::from_oklab(l, a, b, alpha)
+            }
+            ColorSpace::Oklch => {
+                let (from, to) = with_missing_hues(self.to_oklch(), other.to_oklch(), 1, 2);
+                let (l, c, h) = mix(from, to, Some(2));
+                Self::from_oklch(l, c, h, alpha)
+            }
+            ColorSpace::Hsl => {
+                let (from, to) = with_missing_hues(self.to_hsl(), other.to_hsl(), 1, 0);
+                let (h, s, l) = mix(from, to, Some(0));
+                Self::from_hsl(h, s, l, alpha)
+            }
+        };
+        color.space(other.space)
+    }
+}
+
+/// Replaces the hue of an achromatic color with the hue of the other color
+fn with_missing_hues(
+    from: (f32, f32, f32),
+    to: (f32, f32, f32),
+    chroma: usize,
+    hue: usize,
+) -> ((f32, f32, f32), (f32, f32, f32)) {
+    let (mut from, mut to) = ([from.0, from.1, from.2], [to.0, to.1, to.2]);
+    if from[chroma] < ACHROMATIC_THRESHOLD {
+        from[hue] = to[hue];
+    } else if to[chroma] < ACHROMATIC_THRESHOLD {
+        to[hue] = from[hue];
+    }
+    ((from[0], from[1], from[2]), (to[0], to[1], to[2]))
+}
+
+/// Interpolates between hues in degrees along the shortest path around the color wheel
+fn interpolate_hue(from: f32, to: f32, ratio: f32) -> f32 {
+    let delta = (to - from + 180.).rem_euclid(360.) - 180.;
+    (from + delta * ratio).rem_euclid(360.)
+}
+
+/// Converts a gamma encoded sRGB component to linear light
+fn decode_srgb(c: f32) -> f32 {
+    let magnitude = c.abs();
+    let linear = if magnitude <= 0.04045 {
+        magnitude / 12.92
+    } else {
+        ((magnitude + 0.055) / 1.055).powf(2.4)
+    };
+    linear.copysign(c)
+}
+
+/// Converts a linear sRGB component to its gamma encoded form
+fn encode_srgb(c: f32) -> f32 {
+    let magnitude = c.abs();
+    let encoded = if magnitude <= 0.003_130_8 {
+        magnitude * 12.92
+    } else {
+        1.055 * magnitude.powf(1. / 2.4) - 0.055
+    };
+    encoded.copysign(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approximately_equal(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    fn approximately_equal_color(a: Color, b: Color) -> bool {
+        approximately_equal(a.r, b.r)
+            && approximately_equal(a.g, b.g)
+            && approximately_equal(a.b, b.b)
+            && approximately_equal(a.a, b.a)
+    }
+
+    #[test]
+    fn test_conversions() {
+        let (l, a, b) = Color::rgb(1., 0., 0.).to_oklab();
+        assert!(approximately_equal(l, 0.628));
+        assert!(approximately_equal(a, 0.225));
+        assert!(approximately_equal(b, 0.126));
+        let (l, c, _) = Color::WHITE.to_oklch();
+        assert!(approximately_equal(l, 1.));
+        assert!(approximately_equal(c, 0.));
+        assert_eq!(Color::rgb(0., 0.5, 0.5).to_hsl(), (180., 1., 0.25));
+
+        let color = Color::from_rgba8(235, 75, 67, 128);
+        let (r, g, b) = color.to_linear_rgb();
+        let (l, a, ok_b) = color.to_oklab();
+        let (lightness, chroma, hue) = color.to_oklch();
+        let (h, s, hsl_l) = color.to_hsl();
+        for round_trip in [
+            Color::from_linear_rgba(r, g, b, color.a),
+            Color::from_oklab(l, a, ok_b, color.a),
+            Color::from_oklch(lightness, chroma, hue, color.a),
+            Color::from_hsl(h, s, hsl_l, color.a),
+        ] {
+            assert!(approximately_equal_color(round_trip, color));
+            assert_eq!(round_trip.to_rgba8(), [235, 75, 67, 128]);
+        }
+    }
+
+    #[test]
+    fn test_interpolation_spaces() {
+        let black = Color::BLACK;
+        let white = Color::WHITE;
+        let mid = |space| black.interpolated(white.space(space), 0.5);
+
+        assert!(approximately_equal(mid(ColorSpace::Srgb).r, 0.5));
+        assert!(approximately_equal(mid(ColorSpace::LinearSrgb).r, 0.735));
+        assert!(approximately_equal(
+            mid(ColorSpace::Oklab).to_oklab().0,
+            0.5
+        ));
+        assert!(approximately_equal(mid(ColorSpace::Hsl).r, 0.5));
+        assert_eq!(mid(ColorSpace::Oklch).color_space(), ColorSpace::Oklch);
+
+        for space in [
+            ColorSpace::Srgb,
+            ColorSpace::LinearSrgb,
+            ColorSpace::Oklab,
+            ColorSpace::Oklch,
+            ColorSpace::Hsl,
+        ] {
+            let red = Color::rgb(1., 0., 0.);
+            let blue = Color::rgb(0., 0., 1.).space(space);
+            assert!(approximately_equal_color(red.interpolated(blue, 0.), red));
+            assert!(approximately_equal_color(red.interpolated(blue, 1.), blue));
+        }
+    }
+
+    #[test]
+    fn test_hue_interpolation() {
+        // 350° to 10° passes through red rather than around the wheel
+        let from = Color::from_hsl(350., 1., 0.5, 1.);
+        let to = Color::from_hsl(10., 1., 0.5, 1.).space(ColorSpace::Hsl);
+        let (h, s, _) = from.interpolated(to, 0.5).to_hsl();
+        assert!(approximately_equal(h, 0.) || approximately_equal(h, 360.));
+        assert!(approximately_equal(s, 1.));
+
+        // Grey has no hue, so only saturation changes
+        let grey = Color::rgb(0.5, 0.5, 0.5);
+        let (h, _, _) = grey.interpolated(to, 0.5).to_hsl();
+        assert!(approximately_equal(h, 10.));
+        let red = Color::rgb(1., 0., 0.);
+        let (_, _, red_hue) = red.to_oklch();
+        let (_, _, h) = red
+            .interpolated(Color::WHITE.space(ColorSpace::Oklch), 0.5)
+            .to_oklch();
+        assert!(approximately_equal(h, red_hue));
+    }
+
+    #[test]
+    fn test_premultiplied_alpha() {
+        let red = Color::rgb(1., 0., 0.).space(ColorSpace::Srgb);
+        let mid = red.interpolated(Color::TRANSPARENT.space(ColorSpace::Srgb), 0.5);
+        assert!(approximately_equal_color(mid, Color::rgba(1., 0., 0., 0.5)));
+        let mid = Color::TRANSPARENT.interpolated(Color::TRANSPARENT, 0.5);
+        assert_eq!(mid.a, 0.);
+    }
+}
@@ -0,0 +1,11 @@
+//! Implementations of the library's traits for types from other math libraries,
+//! each enabled by the cargo feature of the same name.
+
+#[cfg(feature = "euclid")]
+mod euclid;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
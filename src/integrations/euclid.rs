@@ -0,0 +1,190 @@
+use crate::angle::Angle as LiltAngle;
+use crate::quaternion::Quaternion;
+use crate::traits::{FloatRepresentable, Interpolable};
+use crate::transform::Transform2D as LiltTransform2D;
+use ::euclid::{
+    Angle, Box2D, Box3D, Length, Point2D, Point3D, Rect, Rotation2D, Rotation3D, Scale,
+    SideOffsets2D, Size2D, Size3D, Transform2D, Translation2D, Translation3D, Vector2D, Vector3D,
+};
+
+macro_rules! impl_interpolable_fields {
+    ($t:ident<$($unit:ident),*> { $($field:ident),* }) => {
+        impl<T, $($unit),*> Interpolable for $t<T, $($unit),*>
+        where
+            T: Interpolable,
+        {
+            fn interpolated(&self, other: Self, ratio: f32) -> Self {
+                $t::new($(self.$field.interpolated(other.$field, ratio)),*)
+            }
+        }
+    };
+}
+
+impl_interpolable_fields!(Point2D<U> { x, y });
+impl_interpolable_fields!(Point3D<U> { x, y, z });
+impl_interpolable_fields!(Vector2D<U> { x, y });
+impl_interpolable_fields!(Vector3D<U> { x, y, z });
+impl_interpolable_fields!(Size2D<U> { width, height });
+impl_interpolable_fields!(Size3D<U> { width, height, depth });
+impl_interpolable_fields!(Rect<U> { origin, size });
+impl_interpolable_fields!(Box2D<U> { min, max });
+impl_interpolable_fields!(Box3D<U> { min, max });
+impl_interpolable_fields!(SideOffsets2D<U> { top, right, bottom, left });
+impl_interpolable_fields!(Translation2D<Src, Dst> { x, y });
+impl_interpolable_fields!(Translation3D<Src, Dst> { x, y, z });
+
+impl<T, U> Interpolable for Length<T, U>
+where
+    T: Interpolable,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Length::new(self.0.interpolated(other.0, ratio))
+    }
+}
+
+impl<T, Src, Dst> Interpolable for Scale<T, Src, Dst>
+where
+    T: Interpolable,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Scale::new(self.0.interpolated(other.0, ratio))
+    }
+}
+
+/// Angles are interpolated as plain numbers, like `Angle::lerp`
+/// Use `Rotation2D` or `lilt::Angle` to rotate along the shortest path
+impl<T> Interpolable for Angle<T>
+where
+    T: Interpolable,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Angle {
+            radians: self.radians.interpolated(other.radians, ratio),
+        }
+    }
+}
+
+/// Rotations are interpolated along the shortest path
+impl<Src, Dst> Interpolable for Rotation2D<f32, Src, Dst> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        let angle = LiltAngle::radians(self.angle)
+            .interpolated(LiltAngle::radians(other.angle), ratio)
+            .as_radians();
+        Rotation2D::radians(angle)
+    }
+}
+
+/// Rotations are interpolated along the shortest arc
+impl<Src, Dst> Interpolable for Rotation3D<f32, Src, Dst> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Quaternion::from(*self)
+            .interpolated(other.into(), ratio)
+            .into()
+    }
+}
+
+/// Transforms are decomposed & interpolated like `lilt::Transform2D`
+impl<Src, Dst> Interpolable for Transform2D<f32, Src, Dst> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        LiltTransform2D::from(*self)
+            .interpolated(other.into(), ratio)
+            .into()
+    }
+}
+
+impl<U> FloatRepresentable for Length<f32, U> {
+    fn float_value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl FloatRepresentable for Angle<f32> {
+    fn float_value(&self) -> f32 {
+        self.radians
+    }
+}
+
+impl<Src, Dst> From<Rotation3D<f32, Src, Dst>> for Quaternion {
+    fn from(value: Rotation3D<f32, Src, Dst>) -> Self {
+        Self::new(value.i, value.j, value.k, value.r)
+    }
+}
+
+impl<Src, Dst> From<Quaternion> for Rotation3D<f32, Src, Dst> {
+    fn from(value: Quaternion) -> Self {
+        Rotation3D::quaternion(value.x, value.y, value.z, value.w)
+    }
+}
+
+impl<Src, Dst> From<Transform2D<f32, Src, Dst>> for LiltTransform2D {
+    fn from(value: Transform2D<f32, Src, Dst>) -> Self {
+        Self::new(
+            value.m11, value.m12, value.m21, value.m22, value.m31, value.m32,
+        )
+    }
+}
+
+impl<Src, Dst> From<LiltTransform2D> for Transform2D<f32, Src, Dst> {
+    fn from(value: LiltTransform2D) -> Self {
+        Transform2D::new(
+            value.m11, value.m12, value.m21, value.m22, value.m31, value.m32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::euclid::approxeq::ApproxEq;
+    use ::euclid::default;
+
+    #[test]
+    fn test_euclid_interpolation() {
+        let from = default::Rect::new(
+            default::Point2D::new(0., 0.),
+            default::Size2D::new(10., 10.),
+        );
+        let to = default::Rect::new(
+            default::Point2D::new(10., 20.),
+            default::Size2D::new(20., 0.),
+        );
+        assert_eq!(
+            from.interpolated(to, 0.5),
+            default::Rect::new(
+                default::Point2D::new(5., 10.),
+                default::Size2D::new(15., 5.)
+            )
+        );
+        assert_eq!(
+            default::Length::new(0.0f32).interpolated(default::Length::new(4.), 0.25),
+            default::Length::new(1.)
+        );
+        assert_eq!(default::Length::new(2.0f32).float_value(), 2.);
+
+        let from = default::Rotation2D::radians(350f32.to_radians());
+        let to = default::Rotation2D::radians(10f32.to_radians());
+        let midpoint = from
+            .interpolated(to, 0.5)
+            .angle
+            .to_degrees()
+            .rem_euclid(360.);
+        assert!(midpoint < 1e-3 || 360. - midpoint < 1e-3);
+    }
+
+    #[test]
+    fn test_euclid_transform_interpolation() {
+        let from = default::Transform2D::identity();
+        let to = default::Transform2D::rotation(Angle::degrees(90.0f32))
+            .then_translate(default::Vector2D::new(10., 0.));
+        let midpoint = from.interpolated(to, 0.5);
+        let expected = default::Transform2D::rotation(Angle::degrees(45.0f32))
+            .then_translate(default::Vector2D::new(5., 0.));
+        assert!(midpoint.approx_eq(&expected));
+
+        let from = default::Rotation3D::identity();
+        let to = default::Rotation3D::around_z(Angle::degrees(90.0f32));
+        let midpoint = from.interpolated(to, 0.5);
+        let expected = default::Rotation3D::around_z(Angle::degrees(45.0f32));
+        assert!(midpoint.approx_eq(&expected));
+    }
+}
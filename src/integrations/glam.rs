@@ -0,0 +1,89 @@
+use crate::quaternion::Quaternion;
+use crate::traits::Interpolable;
+use crate::transform::Transform3D;
+use ::glam::{DQuat, DVec2, DVec3, DVec4, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+macro_rules! impl_interpolable_lerp {
+    ($scalar:ty: $($t:ty),*) => {
+        $(
+            impl Interpolable for $t {
+                fn interpolated(&self, other: Self, ratio: f32) -> Self {
+                    self.lerp(other, ratio as $scalar)
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolable_lerp!(f32: Vec2, Vec3, Vec3A, Vec4);
+impl_interpolable_lerp!(f64: DVec2, DVec3, DVec4);
+
+/// Rotations are interpolated along the shortest arc
+impl Interpolable for Quat {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        self.slerp(other, ratio)
+    }
+}
+
+/// Rotations are interpolated along the shortest arc
+impl Interpolable for DQuat {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        self.slerp(other, ratio as f64)
+    }
+}
+
+impl From<Quat> for Quaternion {
+    fn from(value: Quat) -> Self {
+        let [x, y, z, w] = value.to_array();
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Quaternion> for Quat {
+    fn from(value: Quaternion) -> Self {
+        Quat::from_xyzw(value.x, value.y, value.z, value.w)
+    }
+}
+
+impl From<Transform3D> for Mat4 {
+    fn from(value: Transform3D) -> Self {
+        Mat4::from_cols_array_2d(&value.to_matrix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Angle;
+
+    #[test]
+    fn test_glam_interpolation() {
+        assert_eq!(
+            Vec3::ZERO.interpolated(Vec3::new(2., 4., 6.), 0.5),
+            Vec3::new(1., 2., 3.)
+        );
+        assert_eq!(
+            DVec2::ZERO.interpolated(DVec2::new(2., 4.), 0.25),
+            DVec2::new(0.5, 1.)
+        );
+        let quarter = Quat::from_rotation_z(90f32.to_radians());
+        let midpoint = Quat::IDENTITY.interpolated(quarter, 0.5);
+        assert!(midpoint.angle_between(Quat::from_rotation_z(45f32.to_radians())) < 1e-3);
+    }
+
+    #[test]
+    fn test_glam_conversion() {
+        let rotation = Quaternion::from_axis_angle((0., 0., 1.), Angle::degrees(90.));
+        let quat = Quat::from(rotation);
+        assert!(quat.angle_between(Quat::from_rotation_z(90f32.to_radians())) < 1e-3);
+        assert_eq!(Quaternion::from(quat), rotation);
+
+        let transform = Transform3D::identity()
+            .with_translation((1., 2., 3.))
+            .with_rotation(rotation)
+            .with_scale((2., 2., 2.));
+        let point = Mat4::from(transform).transform_point3(Vec3::X);
+        let (x, y, z) = transform.transform_point((1., 0., 0.));
+        assert!(point.abs_diff_eq(Vec3::new(x, y, z), 1e-5));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::quaternion::Quaternion;
+use crate::traits::Interpolable;
+use ::mint::{Point2, Point3, Vector2, Vector3, Vector4};
+
+macro_rules! impl_interpolable_fields {
+    ($t:ident { $($field:ident),* }) => {
+        impl<T> Interpolable for $t<T>
+        where
+            T: Interpolable,
+        {
+            fn interpolated(&self, other: Self, ratio: f32) -> Self {
+                $t {
+                    $($field: self.$field.interpolated(other.$field, ratio)),*
+                }
+            }
+        }
+    };
+}
+
+impl_interpolable_fields!(Vector2 { x, y });
+impl_interpolable_fields!(Vector3 { x, y, z });
+impl_interpolable_fields!(Vector4 { x, y, z, w });
+impl_interpolable_fields!(Point2 { x, y });
+impl_interpolable_fields!(Point3 { x, y, z });
+
+/// Rotations are interpolated along the shortest arc
+impl Interpolable for ::mint::Quaternion<f32> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Quaternion::from(*self)
+            .interpolated(other.into(), ratio)
+            .into()
+    }
+}
+
+impl From<::mint::Quaternion<f32>> for Quaternion {
+    fn from(value: ::mint::Quaternion<f32>) -> Self {
+        Self::new(value.v.x, value.v.y, value.v.z, value.s)
+    }
+}
+
+impl From<Quaternion> for ::mint::Quaternion<f32> {
+    fn from(value: Quaternion) -> Self {
+        ::mint::Quaternion {
+            v: Vector3 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            },
+            s: value.w,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Angle;
+
+    #[test]
+    fn test_mint_interpolation() {
+        let from = Point2 { x: 0.0f32, y: 10. };
+        let to = Point2 { x: 10.0f32, y: 0. };
+        assert_eq!(from.interpolated(to, 0.5), Point2 { x: 5., y: 5. });
+        let from = Vector4::from([0u8, 0, 0, 0]);
+        assert_eq!(
+            from.interpolated(Vector4::from([10, 20, 30, 40]), 0.5),
+            Vector4::from([5, 10, 15, 20])
+        );
+
+        let to = Quaternion::from_axis_angle((1., 0., 0.), Angle::degrees(90.));
+        let midpoint =
+            ::mint::Quaternion::from(Quaternion::identity()).interpolated(to.into(), 0.5);
+        let expected = Quaternion::from_axis_angle((1., 0., 0.), Angle::degrees(45.));
+        assert!((Quaternion::from(midpoint).dot(&expected) - 1.).abs() < 1e-5);
+    }
+}
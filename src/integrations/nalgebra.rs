@@ -0,0 +1,145 @@
+use crate::angle::Angle;
+use crate::quaternion::Quaternion;
+use crate::traits::Interpolable;
+use crate::transform::Transform3D;
+use ::nalgebra::allocator::Allocator;
+use ::nalgebra::{
+    DefaultAllocator, Dim, DimName, Isometry2, Isometry3, Matrix4, OMatrix, OPoint, Scalar,
+    Translation, UnitComplex, UnitQuaternion,
+};
+
+/// Matrices & vectors are interpolated component-wise
+impl<T, R, C> Interpolable for OMatrix<T, R, C>
+where
+    T: Scalar + Interpolable,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<R, C>,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        self.zip_map(&other, |a, b| a.interpolated(b, ratio))
+    }
+}
+
+impl<T, D> Interpolable for OPoint<T, D>
+where
+    T: Scalar + Interpolable,
+    D: DimName,
+    DefaultAllocator: Allocator<D>,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        OPoint::from(self.coords.interpolated(other.coords, ratio))
+    }
+}
+
+impl<T, const D: usize> Interpolable for Translation<T, D>
+where
+    T: Scalar + Interpolable,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Translation::from(self.vector.interpolated(other.vector, ratio))
+    }
+}
+
+/// Rotations are interpolated along the shortest path
+impl Interpolable for UnitComplex<f32> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        let angle = Angle::radians(self.angle())
+            .interpolated(Angle::radians(other.angle()), ratio)
+            .as_radians();
+        UnitComplex::new(angle)
+    }
+}
+
+/// Rotations are interpolated along the shortest arc
+impl Interpolable for UnitQuaternion<f32> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Quaternion::from(*self)
+            .interpolated(other.into(), ratio)
+            .into()
+    }
+}
+
+impl Interpolable for Isometry2<f32> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Isometry2::from_parts(
+            self.translation.interpolated(other.translation, ratio),
+            self.rotation.interpolated(other.rotation, ratio),
+        )
+    }
+}
+
+impl Interpolable for Isometry3<f32> {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Isometry3::from_parts(
+            self.translation.interpolated(other.translation, ratio),
+            self.rotation.interpolated(other.rotation, ratio),
+        )
+    }
+}
+
+impl From<UnitQuaternion<f32>> for Quaternion {
+    fn from(value: UnitQuaternion<f32>) -> Self {
+        let coords = value.into_inner().coords;
+        Self::new(coords.x, coords.y, coords.z, coords.w)
+    }
+}
+
+impl From<Quaternion> for UnitQuaternion<f32> {
+    fn from(value: Quaternion) -> Self {
+        UnitQuaternion::new_normalize(::nalgebra::Quaternion::new(
+            value.w, value.x, value.y, value.z,
+        ))
+    }
+}
+
+impl From<Transform3D> for Matrix4<f32> {
+    fn from(value: Transform3D) -> Self {
+        Matrix4::from_fn(|row, column| value.to_matrix()[column][row])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::nalgebra::{Point3, Vector2, Vector3};
+
+    #[test]
+    fn test_nalgebra_interpolation() {
+        assert_eq!(
+            Vector3::new(0.0f32, 0., 0.).interpolated(Vector3::new(2., 4., 6.), 0.5),
+            Vector3::new(1., 2., 3.)
+        );
+        assert_eq!(
+            Point3::new(0.0f64, 0., 0.).interpolated(Point3::new(4., 8., 12.), 0.25),
+            Point3::new(1., 2., 3.)
+        );
+
+        let from = Isometry2::new(Vector2::new(0., 0.), 350f32.to_radians());
+        let to = Isometry2::new(Vector2::new(10., 0.), 10f32.to_radians());
+        let midpoint = from.interpolated(to, 0.5);
+        assert_eq!(midpoint.translation.vector, Vector2::new(5., 0.));
+        assert!(midpoint.rotation.angle().abs() < 1e-5);
+
+        let from = UnitQuaternion::identity();
+        let to = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 90f32.to_radians());
+        let expected = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 45f32.to_radians());
+        assert!(from.interpolated(to, 0.5).angle_to(&expected) < 1e-3);
+    }
+
+    #[test]
+    fn test_nalgebra_conversion() {
+        let rotation = Quaternion::from_axis_angle((0., 0., 1.), Angle::degrees(90.));
+        let unit = UnitQuaternion::from(rotation);
+        let (x, y, z) = rotation.rotate_vector((1., 0., 0.));
+        assert!((unit * Vector3::x() - Vector3::new(x, y, z)).norm() < 1e-5);
+        assert!(Quaternion::from(unit).dot(&rotation) > 1. - 1e-5);
+
+        let transform = Transform3D::identity()
+            .with_translation((1., 2., 3.))
+            .with_rotation(rotation);
+        let point = Matrix4::from(transform).transform_point(&Point3::new(1., 0., 0.));
+        let (x, y, z) = transform.transform_point((1., 0., 0.));
+        assert!((point - Point3::new(x, y, z)).norm() < 1e-5);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::animated::{Easing, EasingCurve};
+use crate::traits::Interpolable;
+
+/// A path through multiple values, each placed at an offset from 0 to 1 through a transition.
+///
+/// Keyframes are sampled using `Animated<T>.animate_keyframes(...)`, so that every transition
+/// runs through the stops like CSS `@keyframes`. The easing of each stop shapes the segment
+/// leading to it, so the animation itself is usually given a linear easing.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Animated, Easing, Keyframes};
+/// use std::time::Instant;
+///
+/// let pulse = Keyframes::new(0.)
+///     .stop(0.3, 1.2, Easing::EaseOut)
+///     .stop(0.6, 0.9, Easing::EaseInOut)
+///     .stop(1.0, 1.0, Easing::EaseInOut);
+///
+/// let now = Instant::now();
+/// let mut toggle: Animated<bool, Instant> = Animated::new(false).easing(Easing::Linear);
+/// toggle.transition(true, now);
+/// let scale = toggle.animate_keyframes(&pulse, now);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Keyframes<I> {
+    frames: Vec<Keyframe<I>>,
+}
+
+#[derive(Clone, Debug)]
+struct Keyframe<I> {
+    offset: f32,
+    value: I,
+    easing: EasingCurve,
+}
+
+impl<I> Keyframes<I>
+where
+    I: Interpolable + Clone,
+{
+    /// Creates keyframes starting with the given value at an offset of 0
+    pub fn new(value: I) -> Self {
+        Self {
+            frames: vec![Keyframe {
+                offset: 0.,
+                value,
+                easing: Easing::Linear.into(),
+            }],
+        }
+    }
+    /// Adds a stop at the given offset
+    /// The easing is applied to the segment leading from the previous stop to this one
+    pub fn stop(mut self, offset: f32, value: I, easing: impl Into<EasingCurve>) -> Self {
+        let index = self.frames.partition_point(|frame| frame.offset <= offset);
+        self.frames.insert(
+            index,
+            Keyframe {
+                offset,
+                value,
+                easing: easing.into(),
+            },
+        );
+        self
+    }
+    /// Samples the keyframes at the given offset
+    /// Offsets outside of the first & last stops resolve to the first & last values
+    pub fn value(&self, offset: f32) -> I {
+        let first = &self.frames[0];
+        if offset <= first.offset {
+            return first.value.clone();
+        }
+        for segment in self.frames.windows(2) {
+            let (from, to) = (&segment[0], &segment[1]);
+            if offset <= to.offset {
+                let span = to.offset - from.offset;
+                let ratio = if span == 0. {
+                    1.
+                } else {
+                    (offset - from.offset) / span
+                };
+                return from
+                    .value
+                    .interpolated(to.value.clone(), to.easing.value(ratio));
+            }
+        }
+        self.frames[self.frames.len() - 1].value.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Animated;
+
+    #[test]
+    fn test_keyframe_segments() {
+        let keyframes =
+            Keyframes::new(0.)
+                .stop(1., 10., Easing::Linear)
+                .stop(0.5, 20., Easing::Linear);
+
+        assert_eq!(keyframes.value(-1.), 0.);
+        assert_eq!(keyframes.value(0.), 0.);
+        assert_eq!(keyframes.value(0.25), 10.);
+        assert_eq!(keyframes.value(0.5), 20.);
+        assert_eq!(keyframes.value(0.75), 15.);
+        assert_eq!(keyframes.value(1.), 10.);
+        assert_eq!(keyframes.value(2.), 10.);
+    }
+
+    #[test]
+    fn test_keyframe_easing() {
+        let keyframes =
+            Keyframes::new(0.)
+                .stop(0.5, 10., Easing::EaseInQuad)
+                .stop(1., 20., Easing::Linear);
+
+        assert_eq!(keyframes.value(0.25), 2.5);
+        assert_eq!(keyframes.value(0.75), 15.);
+    }
+
+    #[test]
+    fn test_animate_keyframes() {
+        let keyframes =
+            Keyframes::new(0.)
+                .stop(0.5, 100., Easing::Linear)
+                .stop(1., 50., Easing::Linear);
+        let mut anim = Animated::new(false).duration(1000.).easing(Easing::Linear);
+        assert_eq!(anim.animate_keyframes(&keyframes, 0.), 50.);
+
+        anim.transition(true, 0.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 0.), 0.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 250.), 50.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 750.), 75.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 1000.), 50.);
+
+        // Every transition runs through the stops, whichever direction it moves in
+        anim.transition(false, 2000.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 2250.), 50.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 3000.), 50.);
+
+        // Interruptions continue from the value shown, blending into a path which begins
+        // partway along
+        anim.transition(true, 2250.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 2250.), 50.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 2500.), 78.125);
+        assert_eq!(anim.animate_keyframes(&keyframes, 3250.), 50.);
+
+        // Interrupting again continues from the blended value
+        let shown = anim.animate_keyframes(&keyframes, 2500.);
+        anim.transition(false, 2500.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 2500.), shown);
+        assert!(!anim.in_progress(3500.));
+        assert_eq!(anim.animate_keyframes(&keyframes, 3500.), 50.);
+    }
+
+    #[test]
+    fn test_keyframes_use_unit_progress() {
+        // Offsets are relative to the transition rather than the wrapped value
+        let keyframes = Keyframes::new(0.).stop(1., 10., Easing::EaseInQuad);
+        let mut anim = Animated::new(100.).duration(1000.).easing(Easing::EaseOut);
+        anim.transition(300., 0.);
+        assert_eq!(anim.animate_keyframes(&keyframes, 500.), 2.5);
+    }
+}
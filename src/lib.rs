@@ -1,7 +1,49 @@
+mod angle;
+pub use angle::Angle;
+pub use angle::Winding;
 mod animated;
 pub use animated::Animated;
+pub use animated::AnimationEvent;
 pub use animated::Easing;
+pub use animated::EasingCurve;
+pub use animated::EasingFunction;
+pub use animated::Interruption;
+pub use animated::NextChange;
+pub use animated::SharedEasing;
+pub use animated::StepPosition;
+pub use animated::Transition;
+mod animated_value;
+pub use animated_value::AnimatedValue;
+pub mod color;
+mod integrations;
+mod keyframes;
+pub use keyframes::Keyframes;
+mod parse;
+pub use parse::ParseEasingError;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "serde")]
+pub use serialization::Snapshot;
+mod quaternion;
+pub use quaternion::Quaternion;
+mod spring;
+pub use spring::Spring;
+mod timeline;
+pub use timeline::Timeline;
 mod traits;
 pub use traits::AnimationTime;
 pub use traits::FloatRepresentable;
 pub use traits::Interpolable;
+pub use traits::IntoMilliseconds;
+mod transform;
+pub use transform::Transform2D;
+pub use transform::Transform3D;
+
+#[cfg(feature = "derive")]
+pub use lilt_derive::FloatRepresentable;
+#[cfg(feature = "derive")]
+pub use lilt_derive::Interpolable;
+
+// Lets derived implementations refer to `::lilt` within this crate's tests
+#[cfg(all(test, feature = "derive"))]
+extern crate self as lilt;
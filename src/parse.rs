@@ -0,0 +1,514 @@
+use crate::animated::{Easing, EasingCurve, StepPosition};
+use std::fmt;
+use std::str::FromStr;
+
+/// Every `Easing` variant which can be identified by name alone
+const NAMED_EASINGS: [Easing; 31] = [
+    Easing::Linear,
+    Easing::EaseIn,
+    Easing::EaseOut,
+    Easing::EaseInOut,
+    Easing::EaseInQuad,
+    Easing::EaseOutQuad,
+    Easing::EaseInOutQuad,
+    Easing::EaseInCubic,
+    Easing::EaseOutCubic,
+    Easing::EaseInOutCubic,
+    Easing::EaseInQuart,
+    Easing::EaseOutQuart,
+    Easing::EaseInOutQuart,
+    Easing::EaseInQuint,
+    Easing::EaseOutQuint,
+    Easing::EaseInOutQuint,
+    Easing::EaseInExpo,
+    Easing::EaseOutExpo,
+    Easing::EaseInOutExpo,
+    Easing::EaseInCirc,
+    Easing::EaseOutCirc,
+    Easing::EaseInOutCirc,
+    Easing::EaseInBack,
+    Easing::EaseOutBack,
+    Easing::EaseInOutBack,
+    Easing::EaseInElastic,
+    Easing::EaseOutElastic,
+    Easing::EaseInOutElastic,
+    Easing::EaseInBounce,
+    Easing::EaseOutBounce,
+    Easing::EaseInOutBounce,
+];
+
+/// An error returned when an `Easing` or `EasingCurve` can't be parsed from a string
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseEasingError {
+    input: String,
+    reason: String,
+}
+
+impl fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid easing `{}`: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParseEasingError {}
+
+impl Easing {
+    fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            Easing::Linear => "Linear",
+            Easing::EaseIn => "EaseIn",
+            Easing::EaseOut => "EaseOut",
+            Easing::EaseInOut => "EaseInOut",
+            Easing::EaseInQuad => "EaseInQuad",
+            Easing::EaseOutQuad => "EaseOutQuad",
+            Easing::EaseInOutQuad => "EaseInOutQuad",
+            Easing::EaseInCubic => "EaseInCubic",
+            Easing::EaseOutCubic => "EaseOutCubic",
+            Easing::EaseInOutCubic => "EaseInOutCubic",
+            Easing::EaseInQuart => "EaseInQuart",
+            Easing::EaseOutQuart => "EaseOutQuart",
+            Easing::EaseInOutQuart => "EaseInOutQuart",
+            Easing::EaseInQuint => "EaseInQuint",
+            Easing::EaseOutQuint => "EaseOutQuint",
+            Easing::EaseInOutQuint => "EaseInOutQuint",
+            Easing::EaseInExpo => "EaseInExpo",
+            Easing::EaseOutExpo => "EaseOutExpo",
+            Easing::EaseInOutExpo => "EaseInOutExpo",
+            Easing::EaseInCirc => "EaseInCirc",
+            Easing::EaseOutCirc => "EaseOutCirc",
+            Easing::EaseInOutCirc => "EaseInOutCirc",
+            Easing::EaseInBack => "EaseInBack",
+            Easing::EaseOutBack => "EaseOutBack",
+            Easing::EaseInOutBack => "EaseInOutBack",
+            Easing::EaseInElastic => "EaseInElastic",
+            Easing::EaseOutElastic => "EaseOutElastic",
+            Easing::EaseInOutElastic => "EaseInOutElastic",
+            Easing::EaseInBounce => "EaseInBounce",
+            Easing::EaseOutBounce => "EaseOutBounce",
+            Easing::EaseInOutBounce => "EaseInOutBounce",
+            Easing::CubicBezier(..) | Easing::Steps(..) | Easing::Custom(..) => return None,
+        })
+    }
+}
+
+/// Formats easings as either the name of the variant or a CSS timing function,
+/// both of which can be parsed back into an `Easing`
+///
+/// `Easing::Custom` is formatted as `custom`, which can't be parsed.
+impl fmt::Display for Easing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name() {
+            return f.write_str(name);
+        }
+        match self {
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                write!(f, "cubic-bezier({x1}, {y1}, {x2}, {y2})")
+            }
+            Easing::Steps(steps, position) => {
+                let position = match position {
+                    StepPosition::JumpStart => "jump-start",
+                    StepPosition::JumpEnd => "jump-end",
+                    StepPosition::JumpNone => "jump-none",
+                    StepPosition::JumpBoth => "jump-both",
+                };
+                write!(f, "steps({steps}, {position})")
+            }
+            _ => f.write_str("custom"),
+        }
+    }
+}
+
+/// Formats easing curves like an `Easing`, with piecewise linear curves formatted as
+/// the CSS `linear()` timing function
+///
+/// `EasingCurve::Shared` is formatted as `custom`, which can't be parsed.
+impl fmt::Display for EasingCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EasingCurve::Easing(easing) => easing.fmt(f),
+            EasingCurve::PiecewiseLinear(points) => {
+                f.write_str("linear(")?;
+                for (index, (input, output)) in points.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{output} {}%", Percentage(*input))?;
+                }
+                f.write_str(")")
+            }
+            EasingCurve::Shared(_) => f.write_str("custom"),
+        }
+    }
+}
+
+/// Formats a fraction as the shortest percentage which parses back to the same fraction
+struct Percentage(f32);
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Scaling in f64 is exact, so the full precision percentage always parses back
+        let percentage = self.0 as f64 * 100.;
+        for precision in 0..9 {
+            let formatted = format!("{percentage:.precision$}");
+            if formatted
+                .parse::<f64>()
+                .map(|parsed| (parsed / 100.) as f32)
+                == Ok(self.0)
+            {
+                return f.write_str(&formatted);
+            }
+        }
+        write!(f, "{percentage}")
+    }
+}
+
+/// Parses CSS timing functions (`ease-in-out`, `cubic-bezier(...)`, `steps(...)`) as well as
+/// the names of `Easing` variants (`EaseOutBounce`)
+///
+/// The CSS `linear(...)` function is parsed as an `EasingCurve`.
+///
+/// Note that CSS keywords are parsed as their CSS definitions - so `ease-in-out` is a cubic
+/// bézier curve, while `EaseInOut` is the sinusoidal `Easing::EaseInOut`.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::Easing;
+///
+/// let bounce: Easing = "EaseOutBounce".parse().unwrap();
+/// let css: Easing = "cubic-bezier(0.25, 0.1, 0.25, 1)".parse().unwrap();
+/// let steps: Easing = "steps(4, jump-start)".parse().unwrap();
+/// ```
+impl FromStr for Easing {
+    type Err = ParseEasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let easing = match parse(s.trim()) {
+            Ok(EasingCurve::Easing(easing)) => Ok(easing),
+            Ok(_) => Err("`linear` with stops can only be parsed as an `EasingCurve`".to_string()),
+            Err(reason) => Err(reason),
+        };
+        easing.map_err(|reason| ParseEasingError {
+            input: s.to_string(),
+            reason,
+        })
+    }
+}
+
+/// Parses anything an `Easing` can be parsed from, as well as the CSS `linear(...)` function
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::EasingCurve;
+///
+/// let linear: EasingCurve = "linear(0, 0.25 40%, 1)".parse().unwrap();
+/// let bounce: EasingCurve = "EaseOutBounce".parse().unwrap();
+/// ```
+impl FromStr for EasingCurve {
+    type Err = ParseEasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s.trim()).map_err(|reason| ParseEasingError {
+            input: s.to_string(),
+            reason,
+        })
+    }
+}
+
+fn parse(input: &str) -> Result<EasingCurve, String> {
+    if let Some(open) = input.find('(') {
+        let Some(arguments) = input[open + 1..].strip_suffix(')') else {
+            return Err("expected a closing `)`".to_string());
+        };
+        let arguments: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let function = input[..open].trim();
+        return match function.to_ascii_lowercase().as_str() {
+            "cubic-bezier" => parse_cubic_bezier(&arguments).map(EasingCurve::Easing),
+            "steps" => parse_steps(&arguments).map(EasingCurve::Easing),
+            "linear" => parse_linear(&arguments),
+            _ => Err(format!(
+                "unknown function `{function}`, expected `cubic-bezier`, `steps` or `linear`"
+            )),
+        };
+    }
+    let easing = match input.to_ascii_lowercase().as_str() {
+        "linear" => Easing::Linear,
+        "ease" => Easing::CubicBezier(0.25, 0.1, 0.25, 1.),
+        "ease-in" => Easing::CubicBezier(0.42, 0., 1., 1.),
+        "ease-out" => Easing::CubicBezier(0., 0., 0.58, 1.),
+        "ease-in-out" => Easing::CubicBezier(0.42, 0., 0.58, 1.),
+        "step-start" => Easing::Steps(1, StepPosition::JumpStart),
+        "step-end" => Easing::Steps(1, StepPosition::JumpEnd),
+        "custom" => return Err("custom easings can't be parsed".to_string()),
+        _ => NAMED_EASINGS
+            .into_iter()
+            .find(|easing| easing.name() == Some(input))
+            .ok_or_else(|| {
+                "expected a CSS timing function or the name of an `Easing` variant".to_string()
+            })?,
+    };
+    Ok(EasingCurve::Easing(easing))
+}
+
+fn parse_number(argument: &str) -> Result<f32, String> {
+    match argument.parse::<f32>() {
+        Ok(number) if number.is_finite() => Ok(number),
+        _ => Err(format!("expected a number, found `{argument}`")),
+    }
+}
+
+fn parse_percentage(argument: &str) -> Result<f32, String> {
+    argument
+        .strip_suffix('%')
+        .and_then(|number| number.parse::<f64>().ok())
+        .map(|number| (number / 100.) as f32)
+        .filter(|number| number.is_finite())
+        .ok_or_else(|| format!("expected a percentage, found `{argument}`"))
+}
+
+fn parse_cubic_bezier(arguments: &[&str]) -> Result<Easing, String> {
+    let [x1, y1, x2, y2] = arguments else {
+        return Err(format!(
+            "`cubic-bezier` expects 4 arguments, found {}",
+            arguments.len()
+        ));
+    };
+    let (x1, y1, x2, y2) = (
+        parse_number(x1)?,
+        parse_number(y1)?,
+        parse_number(x2)?,
+        parse_number(y2)?,
+    );
+    if !(0. ..=1.).contains(&x1) || !(0. ..=1.).contains(&x2) {
+        return Err("`cubic-bezier` x values must be between 0 and 1".to_string());
+    }
+    Ok(Easing::CubicBezier(x1, y1, x2, y2))
+}
+
+fn parse_steps(arguments: &[&str]) -> Result<Easing, String> {
+    let (steps, position) = match arguments {
+        [steps] => (steps, StepPosition::JumpEnd),
+        [steps, position] => (
+            steps,
+            match *position {
+                "jump-start" | "start" => StepPosition::JumpStart,
+                "jump-end" | "end" => StepPosition::JumpEnd,
+                "jump-none" => StepPosition::JumpNone,
+                "jump-both" => StepPosition::JumpBoth,
+                _ => {
+                    return Err(format!(
+                        "unknown step position `{position}`, expected `jump-start`, `jump-end`, `jump-none` or `jump-both`"
+                    ))
+                }
+            },
+        ),
+        _ => {
+            return Err(format!(
+                "`steps` expects 1 or 2 arguments, found {}",
+                arguments.len()
+            ))
+        }
+    };
+    let steps = steps
+        .parse::<u32>()
+        .map_err(|_| format!("expected a whole number of steps, found `{steps}`"))?;
+    match (steps, position) {
+        (0, _) => Err("`steps` requires at least 1 step".to_string()),
+        (1, StepPosition::JumpNone) => {
+            Err("`steps` with `jump-none` requires at least 2 steps".to_string())
+        }
+        _ => Ok(Easing::Steps(steps, position)),
+    }
+}
+
+fn parse_linear(arguments: &[&str]) -> Result<EasingCurve, String> {
+    if arguments.len() < 2 {
+        return Err("`linear` expects at least 2 stops".to_string());
+    }
+    // Each stop has an output & up to two input percentages
+    let mut stops: Vec<(Option<f32>, f32)> = Vec::new();
+    for argument in arguments {
+        let mut parts = argument.split_whitespace();
+        let output = parse_number(parts.next().unwrap_or_default())?;
+        let inputs = parts.map(parse_percentage).collect::<Result<Vec<_>, _>>()?;
+        match inputs[..] {
+            [] => stops.push((None, output)),
+            [input] => stops.push((Some(input), output)),
+            [start, end] => {
+                stops.push((Some(start), output));
+                stops.push((Some(end), output));
+            }
+            _ => return Err(format!("stop `{argument}` has more than 2 percentages")),
+        }
+    }
+    // Missing inputs are spread evenly between the known inputs around them,
+    // & inputs can't be less than any input before them
+    let last = stops.len() - 1;
+    stops[0].0.get_or_insert(0.);
+    stops[last].0.get_or_insert(1.);
+    let mut largest = f32::MIN;
+    for stop in stops.iter_mut() {
+        if let Some(input) = stop.0.as_mut() {
+            *input = f32::max(*input, largest);
+            largest = *input;
+        }
+    }
+    let mut index = 0;
+    while index < last {
+        let next = index
+            + 1
+            + stops[index + 1..]
+                .iter()
+                .position(|s| s.0.is_some())
+                .unwrap();
+        let (start, end) = (stops[index].0.unwrap(), stops[next].0.unwrap());
+        for (offset, stop) in stops[index + 1..next].iter_mut().enumerate() {
+            let fraction = (offset + 1) as f32 / (next - index) as f32;
+            stop.0 = Some(start + (end - start) * fraction);
+        }
+        index = next;
+    }
+    Ok(EasingCurve::PiecewiseLinear(
+        stops
+            .into_iter()
+            .map(|(input, output)| (input.unwrap(), output))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named() {
+        for easing in NAMED_EASINGS {
+            assert_eq!(easing.to_string().parse::<Easing>(), Ok(easing));
+        }
+        assert_eq!(" EaseOutBounce ".parse(), Ok(Easing::EaseOutBounce));
+        assert_eq!("linear".parse(), Ok(Easing::Linear));
+    }
+
+    #[test]
+    fn test_parse_css_keywords() {
+        assert_eq!(
+            "ease-in-out".parse(),
+            Ok(Easing::CubicBezier(0.42, 0., 0.58, 1.))
+        );
+        assert_eq!("EASE".parse(), Ok(Easing::CubicBezier(0.25, 0.1, 0.25, 1.)));
+        assert_eq!(
+            "step-start".parse(),
+            Ok(Easing::Steps(1, StepPosition::JumpStart))
+        );
+    }
+
+    #[test]
+    fn test_parse_functions() {
+        assert_eq!(
+            "cubic-bezier(0.68, -0.6, 0.32, 1.6)".parse(),
+            Ok(Easing::CubicBezier(0.68, -0.6, 0.32, 1.6))
+        );
+        assert_eq!(
+            "steps(4)".parse(),
+            Ok(Easing::Steps(4, StepPosition::JumpEnd))
+        );
+        assert_eq!(
+            "steps( 3 , jump-both )".parse(),
+            Ok(Easing::Steps(3, StepPosition::JumpBoth))
+        );
+        assert_eq!(
+            "linear(0, 0.25 40%, 1)".parse(),
+            Ok(EasingCurve::linear([(0., 0.), (0.4, 0.25), (1., 1.)]))
+        );
+        assert_eq!(
+            "linear(0, 0.5 25% 75%, 0.75, 1)".parse(),
+            Ok(EasingCurve::linear([
+                (0., 0.),
+                (0.25, 0.5),
+                (0.75, 0.5),
+                (0.875, 0.75),
+                (1., 1.)
+            ]))
+        );
+        assert_eq!(
+            "ease-in".parse(),
+            Ok(EasingCurve::Easing(Easing::CubicBezier(0.42, 0., 1., 1.)))
+        );
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        for easing in [
+            Easing::CubicBezier(0.25, 0.1, 0.25, 1.),
+            Easing::Steps(4, StepPosition::JumpNone),
+        ] {
+            assert_eq!(easing.to_string().parse::<Easing>(), Ok(easing));
+        }
+        let linear = EasingCurve::linear([(0., 0.), (0.4, 0.25), (1., 1.)]);
+        assert_eq!(linear.to_string().parse::<EasingCurve>(), Ok(linear));
+        assert_eq!(
+            EasingCurve::linear([(0., 0.), (0.007, 0.5), (1., 1.)]).to_string(),
+            "linear(0 0%, 0.5 0.7%, 1 100%)"
+        );
+    }
+
+    #[test]
+    fn test_linear_round_trip() {
+        let inputs = (0..=1000).map(|index| index as f32 / 1000.).chain([
+            1. / 3.,
+            f32::EPSILON,
+            1. - f32::EPSILON,
+            0.123_456_79,
+        ]);
+        for input in inputs {
+            let linear = EasingCurve::linear([(0., 0.), (input, input), (1., 1.)]);
+            assert_eq!(linear.to_string().parse::<EasingCurve>(), Ok(linear));
+        }
+        assert_eq!(
+            Easing::Steps(2, StepPosition::JumpStart).to_string(),
+            "steps(2, jump-start)"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| input.parse::<Easing>().unwrap_err().to_string();
+        assert_eq!(
+            error("bounce"),
+            "invalid easing `bounce`: expected a CSS timing function or the name of an `Easing` variant"
+        );
+        assert_eq!(
+            error("cubic-bezier(0.1, 0.2)"),
+            "invalid easing `cubic-bezier(0.1, 0.2)`: `cubic-bezier` expects 4 arguments, found 2"
+        );
+        assert_eq!(
+            error("cubic-bezier(2, 0, 0.5, 1)"),
+            "invalid easing `cubic-bezier(2, 0, 0.5, 1)`: `cubic-bezier` x values must be between 0 and 1"
+        );
+        assert_eq!(
+            error("steps(0)"),
+            "invalid easing `steps(0)`: `steps` requires at least 1 step"
+        );
+        assert_eq!(
+            error("steps(2, middle)"),
+            "invalid easing `steps(2, middle)`: unknown step position `middle`, expected `jump-start`, `jump-end`, `jump-none` or `jump-both`"
+        );
+        assert_eq!(
+            error("linear(0, 1 half)"),
+            "invalid easing `linear(0, 1 half)`: expected a percentage, found `half`"
+        );
+        assert_eq!(
+            error("steps(2"),
+            "invalid easing `steps(2`: expected a closing `)`"
+        );
+        assert_eq!(
+            error("custom"),
+            "invalid easing `custom`: custom easings can't be parsed"
+        );
+        assert_eq!(
+            error("linear(0, 1)"),
+            "invalid easing `linear(0, 1)`: `linear` with stops can only be parsed as an `EasingCurve`"
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use crate::angle::Angle;
+use crate::traits::Interpolable;
+use std::ops::{Mul, Neg};
+
+/// A unit quaternion representing a 3D rotation
+///
+/// Quaternions are interpolated using spherical linear interpolation, which rotates at
+/// a constant speed along the shortest arc between two orientations.
+///
+/// Rotations are right-handed, so a positive angle around an axis pointing towards
+/// the viewer rotates counter-clockwise.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Angle, Animated, Quaternion};
+/// use std::time::Instant;
+///
+/// let front = Quaternion::identity();
+/// let side = Quaternion::from_axis_angle((0., 1., 0.), Angle::degrees(90.));
+///
+/// let now = Instant::now();
+/// let mut turned: Animated<bool, Instant> = Animated::new(false);
+/// turned.transition(true, now);
+/// let facing = turned.animate_bool(front, side, now).rotate_vector((0., 0., -1.));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+/// Above this dot product orientations are close enough that normalized linear
+/// interpolation is indistinguishable from spherical interpolation
+const NLERP_THRESHOLD: f32 = 0.9995;
+
+impl Quaternion {
+    /// Creates a quaternion from its components
+    /// Rotations should be normalized, see `Quaternion::normalized`
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+    /// A quaternion which doesn't rotate
+    pub fn identity() -> Self {
+        Self::new(0., 0., 0., 1.)
+    }
+    /// Creates a rotation around the given axis
+    pub fn from_axis_angle(axis: (f32, f32, f32), angle: Angle) -> Self {
+        let (x, y, z) = axis;
+        let length = (x * x + y * y + z * z).sqrt();
+        if length == 0. {
+            return Self::identity();
+        }
+        let (sin, cos) = (angle.as_radians() / 2.).sin_cos();
+        let scale = sin / length;
+        Self::new(x * scale, y * scale, z * scale, cos)
+    }
+    /// The axis & angle of the rotation
+    /// The axis of a rotation without any angle is arbitrary
+    pub fn to_axis_angle(&self) -> ((f32, f32, f32), Angle) {
+        let rotation = self.normalized();
+        let sin = (1. - rotation.w * rotation.w).max(0.).sqrt();
+        let angle = Angle::radians(2. * rotation.w.clamp(-1., 1.).acos());
+        if sin < 1e-6 {
+            return ((1., 0., 0.), angle);
+        }
+        (
+            (rotation.x / sin, rotation.y / sin, rotation.z / sin),
+            angle,
+        )
+    }
+    /// The four dimensional dot product, which measures how similar two rotations are
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+    /// The length of the quaternion, which is 1 for rotations
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+    /// The quaternion scaled to a length of 1
+    pub fn normalized(&self) -> Self {
+        let length = self.length();
+        if length == 0. {
+            return Self::identity();
+        }
+        Self::new(
+            self.x / length,
+            self.y / length,
+            self.z / length,
+            self.w / length,
+        )
+    }
+    /// The opposite rotation
+    pub fn inverse(&self) -> Self {
+        let length_squared = self.dot(self);
+        Self::new(
+            -self.x / length_squared,
+            -self.y / length_squared,
+            -self.z / length_squared,
+            self.w / length_squared,
+        )
+    }
+    /// Rotates a vector
+    pub fn rotate_vector(&self, vector: (f32, f32, f32)) -> (f32, f32, f32) {
+        let axis = (self.x, self.y, self.z);
+        let twice = cross(axis, vector);
+        let twice = (2. * twice.0, 2. * twice.1, 2. * twice.2);
+        let turned = cross(axis, twice);
+        (
+            vector.0 + self.w * twice.0 + turned.0,
+            vector.1 + self.w * twice.1 + turned.1,
+            vector.2 + self.w * twice.2 + turned.2,
+        )
+    }
+    /// Interpolates along the shortest arc between two rotations at a constant speed
+    pub fn slerp(&self, other: Self, ratio: f32) -> Self {
+        let (other, dot) = self.nearest(other);
+        if dot > NLERP_THRESHOLD {
+            return self.nlerp(other, ratio);
+        }
+        let angle = dot.clamp(-1., 1.).acos();
+        let sin = angle.sin();
+        let from = ((1. - ratio) * angle).sin() / sin;
+        let to = (ratio * angle).sin() / sin;
+        self.weighted(from, other, to)
+    }
+    /// Interpolates linearly & normalizes the result
+    ///
+    /// Faster than `slerp` & follows the same path, though the rotation speeds up towards the
+    /// middle when rotations are far apart.
+    pub fn nlerp(&self, other: Self, ratio: f32) -> Self {
+        let (other, _) = self.nearest(other);
+        self.weighted(1. - ratio, other, ratio).normalized()
+    }
+    /// Both `q` & `-q` represent the same rotation, so use the one closest to this rotation
+    /// in order to take the shortest arc
+    fn nearest(&self, other: Self) -> (Self, f32) {
+        let dot = self.dot(&other);
+        if dot < 0. {
+            (-other, -dot)
+        } else {
+            (other, dot)
+        }
+    }
+    fn weighted(&self, weight: f32, other: Self, other_weight: f32) -> Self {
+        Self::new(
+            self.x * weight + other.x * other_weight,
+            self.y * weight + other.y * other_weight,
+            self.z * weight + other.z * other_weight,
+            self.w * weight + other.w * other_weight,
+        )
+    }
+}
+
+fn cross(a: (f32, f32, f32), b: (f32, f32, f32)) -> (f32, f32, f32) {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Combines two rotations, so that `a * b` rotates by `b` & then by `a`
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Interpolable for Quaternion {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        self.slerp(other, ratio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approximately_equal(a: (f32, f32, f32), b: (f32, f32, f32)) -> bool {
+        (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4 && (a.2 - b.2).abs() < 1e-4
+    }
+
+    fn rotation_y(degrees: f32) -> Quaternion {
+        Quaternion::from_axis_angle((0., 1., 0.), Angle::degrees(degrees))
+    }
+
+    #[test]
+    fn test_rotation() {
+        let quarter = Quaternion::from_axis_angle((0., 0., 2.), Angle::degrees(90.));
+        assert!(approximately_equal(
+            quarter.rotate_vector((1., 0., 0.)),
+            (0., 1., 0.)
+        ));
+        let (axis, angle) = quarter.to_axis_angle();
+        assert!(approximately_equal(axis, (0., 0., 1.)));
+        assert!((angle.as_degrees() - 90.).abs() < 1e-3);
+
+        let combined = rotation_y(90.) * quarter;
+        assert!(approximately_equal(
+            combined.rotate_vector((1., 0., 0.)),
+            (0., 1., 0.)
+        ));
+        assert!(approximately_equal(
+            combined.rotate_vector((0., 0., 1.)),
+            (1., 0., 0.)
+        ));
+        assert!(approximately_equal(
+            (combined * combined.inverse()).rotate_vector((1., 2., 3.)),
+            (1., 2., 3.)
+        ));
+    }
+
+    #[test]
+    fn test_slerp() {
+        let from = Quaternion::identity();
+        let to = rotation_y(120.);
+        for ratio in [0., 0.25, 0.5, 1.] {
+            let expected = rotation_y(120. * ratio).rotate_vector((1., 0., 0.));
+            let slerped = from.interpolated(to, ratio);
+            assert!((slerped.length() - 1.).abs() < 1e-5);
+            assert!(approximately_equal(
+                slerped.rotate_vector((1., 0., 0.)),
+                expected
+            ));
+        }
+        // Overshooting easings extrapolate along the same arc
+        assert!(approximately_equal(
+            from.interpolated(to, 1.25).rotate_vector((1., 0., 0.)),
+            rotation_y(150.).rotate_vector((1., 0., 0.))
+        ));
+    }
+
+    #[test]
+    fn test_shortest_arc() {
+        // Negated quaternions represent the same rotation, but would take the long way around
+        let from = rotation_y(10.);
+        let to = -rotation_y(30.);
+        for midpoint in [from.slerp(to, 0.5), from.nlerp(to, 0.5)] {
+            assert!(approximately_equal(
+                midpoint.rotate_vector((1., 0., 0.)),
+                rotation_y(20.).rotate_vector((1., 0., 0.))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_nlerp() {
+        let from = rotation_y(10.);
+        let to = rotation_y(10.01);
+        let slerped = from.slerp(to, 0.5);
+        assert!((slerped.length() - 1.).abs() < 1e-5);
+        assert!(approximately_equal(
+            slerped.rotate_vector((1., 0., 0.)),
+            rotation_y(10.005).rotate_vector((1., 0., 0.))
+        ));
+    }
+}
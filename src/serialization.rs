@@ -0,0 +1,200 @@
+use crate::animated::{Animated, Easing, EasingCurve};
+use crate::traits::{AnimationTime, FloatRepresentable};
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Easings are serialized in the same format used by `Display` & `FromStr`,
+/// e.g. `EaseOutBounce` or `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+///
+/// `Easing::Custom` can't be represented & fails to serialize.
+impl Serialize for Easing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Easing::Custom(_) = self {
+            return Err(S::Error::custom("custom easings can't be serialized"));
+        }
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Easing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Easing curves are serialized like an `Easing`, with piecewise linear curves
+/// serialized as the CSS `linear()` timing function.
+///
+/// `EasingCurve::Shared` & `Easing::Custom` can't be represented & fail to serialize.
+impl Serialize for EasingCurve {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            EasingCurve::Easing(easing) => easing.serialize(serializer),
+            EasingCurve::PiecewiseLinear(_) => serializer.serialize_str(&self.to_string()),
+            EasingCurve::Shared(_) => Err(S::Error::custom("custom easings can't be serialized")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EasingCurve {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// A time stored as the number of milliseconds before a snapshot was taken
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+struct SnapshotTime(f64);
+
+impl AnimationTime for SnapshotTime {
+    fn elapsed_since(self, time: Self) -> f32 {
+        (time.0 - self.0) as f32
+    }
+    fn seconds_since(self, time: Self) -> f64 {
+        (time.0 - self.0) / 1000.
+    }
+}
+
+/// A serializable capture of an `Animated` value, with every time stored relative to
+/// the time the snapshot was taken
+///
+/// `Animated<T, Time>` can be serialized directly when `Time` is serializable. Times such as
+/// `std::time::Instant` are only meaningful within a single process, so they're captured
+/// using `Animated::snapshot` & later brought back using `Animated::restore`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Snapshot<T>
+where
+    T: FloatRepresentable + Clone + Copy + PartialEq,
+{
+    animated: Animated<T, SnapshotTime>,
+}
+
+impl<T, Time> Animated<T, Time>
+where
+    T: FloatRepresentable + Clone + Copy + PartialEq,
+    Time: AnimationTime,
+{
+    /// Captures the animation relative to the given time, so that it can be serialized
+    pub fn snapshot(&self, now: Time) -> Snapshot<T> {
+        // Anchoring at `now` keeps the snapshot independent of the playback rate
+        let mut animated = self.clone();
+        animated.rebase(now);
+        Snapshot {
+            animated: animated.map_time(&|transition_time, offset_ms| {
+                (
+                    SnapshotTime(now.seconds_since(transition_time) * 1000.),
+                    offset_ms,
+                )
+            }),
+        }
+    }
+    /// Restores an animation from a snapshot, continuing from where it was when the
+    /// snapshot was taken as of the given time
+    pub fn restore(snapshot: Snapshot<T>, now: Time) -> Self {
+        snapshot
+            .animated
+            .map_time(&|SnapshotTime(before_ms), offset_ms| (now, offset_ms + before_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Spring, StepPosition};
+
+    #[test]
+    fn test_easing_serialization() {
+        for easing in [
+            Easing::EaseOutBounce,
+            Easing::CubicBezier(0.25, 0.1, 0.25, 1.),
+            Easing::Steps(3, StepPosition::JumpBoth),
+        ] {
+            let json = serde_json::to_string(&easing).unwrap();
+            assert_eq!(serde_json::from_str::<Easing>(&json).unwrap(), easing);
+        }
+        assert_eq!(
+            serde_json::to_string(&Easing::EaseOutBounce).unwrap(),
+            "\"EaseOutBounce\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Easing>("\"ease-in-out\"").unwrap(),
+            Easing::CubicBezier(0.42, 0., 0.58, 1.)
+        );
+        assert!(serde_json::to_string(&Easing::Custom(|x| x)).is_err());
+        assert!(serde_json::to_string(&EasingCurve::function(|x| x)).is_err());
+        assert!(serde_json::from_str::<Easing>("\"bounce\"").is_err());
+
+        let linear = EasingCurve::linear([(0., 0.), (0.4, 0.25), (1., 1.)]);
+        let json = serde_json::to_string(&linear).unwrap();
+        assert_eq!(json, "\"linear(0 0%, 0.25 40%, 1 100%)\"");
+        assert_eq!(serde_json::from_str::<EasingCurve>(&json).unwrap(), linear);
+    }
+
+    #[test]
+    fn test_animated_serialization() {
+        let mut anim = Animated::new(0.)
+            .duration(1000.)
+            .easing(Easing::Linear)
+            .asymmetric_spring(Spring::smooth())
+            .delay(100.);
+        anim.transition(10., 0.);
+        let json = serde_json::to_string(&anim).unwrap();
+        let mut restored: Animated<f32, f32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.animate_wrapped(600.), 5.);
+        assert!(restored.in_progress(1099.));
+        assert!(!restored.in_progress(1100.));
+
+        restored.transition(0., 2000.);
+        let settled = 2100. + Spring::smooth().settling_duration_ms(0.);
+        assert!(restored.in_progress(settled - 1.));
+        assert!(!restored.in_progress(settled));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut anim = Animated::new(false).duration(1000.).easing(Easing::Linear);
+        anim.transition(true, 0.);
+        let json = serde_json::to_string(&anim.snapshot(250.)).unwrap();
+
+        // Restored at a completely different time, continuing from 25%
+        let snapshot: Snapshot<bool> = serde_json::from_str(&json).unwrap();
+        let restored = Animated::restore(snapshot, 5000.);
+        assert!(restored.value);
+        assert_eq!(restored.animate_bool(0., 1., 5000.), 0.25);
+        assert_eq!(restored.animate_bool(0., 1., 5500.), 0.75);
+        assert!(!restored.in_progress(5750.));
+    }
+
+    #[test]
+    fn test_snapshot_playback() {
+        let mut anim = Animated::new(false).duration(1000.).easing(Easing::Linear);
+        anim.transition(true, 0.);
+        anim.set_speed(2., 0.);
+        let restored = Animated::restore(anim.snapshot(250.), 5000.);
+        assert_eq!(restored.animate_bool(0., 1., 5000.), 0.5);
+        assert_eq!(restored.animate_bool(0., 1., 5125.), 0.75);
+
+        anim.pause(250.);
+        let restored = Animated::restore(anim.snapshot(400.), 5000.);
+        assert!(restored.is_paused());
+        assert_eq!(restored.animate_bool(0., 1., 6000.), 0.5);
+    }
+}
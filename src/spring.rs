@@ -0,0 +1,247 @@
+use crate::traits::IntoMilliseconds;
+
+/// A physically modeled spring which can be used in place of a duration & easing.
+///
+/// Springs are solved analytically as a function of time - so like every other
+/// animation in this library they don't require any 'tick'. When a spring animation
+/// is interrupted, its velocity is carried into the next transition.
+///
+/// `stiffness`, `damping` & `mass` follow the usual physical model (in seconds), so
+/// values from other animation tools can be used directly. Stiffness & mass must be
+/// positive, & springs with little or no damping are given a minimal damping ratio so that
+/// they always come to rest.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Animated, Spring};
+/// use std::time::Instant;
+///
+/// let toggle: Animated<bool, Instant> = Animated::new(false).spring(Spring::snappy());
+/// let custom: Animated<bool, Instant> = Animated::new(false).spring(Spring::new(170., 26., 1.));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+}
+
+/// Distance from the destination (in unit progress) at which a spring is considered settled
+const SETTLING_THRESHOLD: f32 = 0.001;
+
+/// Undamped springs oscillate forever, so damping ratios are clamped to at least this
+const MIN_DAMPING_RATIO: f32 = 0.01;
+
+impl Spring {
+    /// Creates a spring from physical parameters
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass,
+        }
+    }
+    /// Creates a spring from a perceptual response & damping fraction
+    ///
+    /// `response` is the period of the spring if it were undamped, given as a `Duration`
+    /// or a number of milliseconds. `damping_fraction` is 1 for a critically damped spring
+    /// & approaches 0 as the spring gets bouncier.
+    pub fn with_response(response: impl IntoMilliseconds, damping_fraction: f32) -> Self {
+        let response = response.into_milliseconds() / 1000.;
+        let angular_frequency = std::f32::consts::TAU / response;
+        Self {
+            stiffness: angular_frequency * angular_frequency,
+            damping: 2. * damping_fraction * angular_frequency,
+            mass: 1.,
+        }
+    }
+    /// A critically damped spring without any bounce
+    pub fn smooth() -> Self {
+        Self::with_response(500., 1.)
+    }
+    /// A spring with a barely noticeable bounce
+    pub fn snappy() -> Self {
+        Self::with_response(500., 0.85)
+    }
+    /// A spring with a visible bounce
+    pub fn bouncy() -> Self {
+        Self::with_response(500., 0.7)
+    }
+
+    fn angular_frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    fn damping_ratio(&self) -> f32 {
+        f32::max(
+            MIN_DAMPING_RATIO,
+            self.damping / (2. * (self.stiffness * self.mass).sqrt()),
+        )
+    }
+
+    /// Solves the spring for a transition from 0 to 1, returning the unit progress &
+    /// velocity (unit progress per millisecond) at the given time.
+    pub(crate) fn solve(&self, elapsed_ms: f32, velocity: f32) -> (f32, f32) {
+        let t = elapsed_ms / 1000.;
+        let displacement = -1.;
+        let velocity = velocity * 1000.;
+        let w0 = self.angular_frequency();
+        let zeta = self.damping_ratio();
+        let (x, v) = match Solution::new(w0, zeta, displacement, velocity) {
+            Solution::Underdamped {
+                decay,
+                frequency,
+                a,
+                b,
+            } => {
+                let envelope = (-decay * t).exp();
+                let (sin, cos) = (frequency * t).sin_cos();
+                (
+                    envelope * (a * cos + b * sin),
+                    envelope
+                        * ((b * frequency - decay * a) * cos - (a * frequency + decay * b) * sin),
+                )
+            }
+            Solution::CriticallyDamped { decay, a, b } => {
+                let envelope = (-decay * t).exp();
+                (envelope * (a + b * t), envelope * (b - decay * (a + b * t)))
+            }
+            Solution::Overdamped { r1, r2, c1, c2 } => {
+                let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+                (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+            }
+        };
+        (1. + x, v / 1000.)
+    }
+
+    /// The time in milliseconds after which the spring is indistinguishable from its destination
+    pub(crate) fn settling_duration_ms(&self, velocity: f32) -> f32 {
+        let w0 = self.angular_frequency();
+        let zeta = self.damping_ratio();
+        let seconds = match Solution::new(w0, zeta, -1., velocity * 1000.) {
+            Solution::Underdamped { decay, a, b, .. } => {
+                (a.hypot(b) / SETTLING_THRESHOLD).ln() / decay
+            }
+            Solution::CriticallyDamped { decay, a, b } => {
+                // The envelope (|a| + |b|t)e^(-decay * t) has no closed form inverse,
+                // but converges quickly when iterated.
+                let mut t = (a.abs() / SETTLING_THRESHOLD).ln() / decay;
+                for _ in 0..8 {
+                    t = ((a.abs() + b.abs() * t) / SETTLING_THRESHOLD).ln() / decay;
+                }
+                t
+            }
+            Solution::Overdamped { r1, c1, c2, .. } => {
+                ((c1.abs() + c2.abs()) / SETTLING_THRESHOLD).ln() / -r1
+            }
+        };
+        f32::max(0., seconds * 1000.)
+    }
+}
+
+enum Solution {
+    Underdamped {
+        decay: f32,
+        frequency: f32,
+        a: f32,
+        b: f32,
+    },
+    CriticallyDamped {
+        decay: f32,
+        a: f32,
+        b: f32,
+    },
+    Overdamped {
+        r1: f32,
+        r2: f32,
+        c1: f32,
+        c2: f32,
+    },
+}
+
+impl Solution {
+    fn new(w0: f32, zeta: f32, displacement: f32, velocity: f32) -> Self {
+        if (zeta - 1.).abs() < 1e-4 {
+            Solution::CriticallyDamped {
+                decay: w0,
+                a: displacement,
+                b: velocity + w0 * displacement,
+            }
+        } else if zeta < 1. {
+            let frequency = w0 * (1. - zeta * zeta).sqrt();
+            Solution::Underdamped {
+                decay: zeta * w0,
+                frequency,
+                a: displacement,
+                b: (velocity + zeta * w0 * displacement) / frequency,
+            }
+        } else {
+            let root = (zeta * zeta - 1.).sqrt();
+            let r1 = -w0 * (zeta - root);
+            let r2 = -w0 * (zeta + root);
+            let c2 = (velocity - r1 * displacement) / (r2 - r1);
+            Solution::Overdamped {
+                r1,
+                r2,
+                c1: displacement - c2,
+                c2,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spring_starts_at_origin() {
+        for spring in [
+            Spring::smooth(),
+            Spring::snappy(),
+            Spring::new(100., 40., 1.),
+        ] {
+            let (position, velocity) = spring.solve(0., 0.);
+            assert!(position.abs() < 1e-6);
+            assert!(velocity.abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_spring_settles() {
+        for spring in [
+            Spring::smooth(),
+            Spring::bouncy(),
+            Spring::new(100., 40., 1.),
+            // Undamped
+            Spring::new(100., 0., 1.),
+        ] {
+            let settled = spring.settling_duration_ms(0.);
+            assert!(settled.is_finite());
+            let (position, _) = spring.solve(settled, 0.);
+            assert!((position - 1.).abs() <= SETTLING_THRESHOLD + 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_spring_initial_velocity() {
+        let spring = Spring::snappy();
+        let (_, velocity) = spring.solve(0., -0.01);
+        assert!((velocity + 0.01).abs() < 1e-6);
+        assert!(spring.settling_duration_ms(-0.01) > spring.settling_duration_ms(0.));
+    }
+
+    #[test]
+    fn test_bouncy_spring_overshoots() {
+        let spring = Spring::bouncy();
+        let settled = spring.settling_duration_ms(0.);
+        let overshoot = (0..100)
+            .map(|i| spring.solve(settled * i as f32 / 100., 0.).0)
+            .fold(0., f32::max);
+        assert!(overshoot > 1.);
+        let (position, _) = Spring::smooth().solve(100., 0.);
+        assert!(position < 1.);
+    }
+}
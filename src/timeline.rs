@@ -0,0 +1,328 @@
+use crate::animated::Animated;
+use crate::traits::{AnimationTime, FloatRepresentable, IntoMilliseconds};
+
+/// Composes multiple animations into sequences & parallel groups which transition together
+///
+/// Each child is scheduled at an offset from the start of the timeline. Since every child
+/// transitions at the same time & is offset by a delay, children never drift apart.
+///
+/// Transitions towards a lower `float_value` play the timeline in reverse, so the last
+/// animation to finish is the first to animate back, using any asymmetric durations.
+/// Interrupted timelines continue from where each child is, skipping children which haven't
+/// moved yet.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Animated, Timeline};
+/// use std::time::Instant;
+///
+/// let mut timeline: Timeline<bool, Instant> = Timeline::new()
+///     .then(Animated::new(false).duration(300.))
+///     .with(Animated::new(false).duration(500.))
+///     .then(Animated::new(false).duration(300.))
+///     .stagger(30., (0..10).map(|_| Animated::new(false).duration(300.)));
+///
+/// let now = Instant::now();
+/// timeline.transition(true, now);
+/// let widths: Vec<f32> = timeline
+///     .children()
+///     .iter()
+///     .map(|child| child.animate_bool(0., 100., now))
+///     .collect();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Timeline<T, Time>
+where
+    T: FloatRepresentable + Clone + Copy + PartialEq,
+    Time: AnimationTime,
+{
+    children: Vec<Animated<T, Time>>,
+    /// The delay of each child when the timeline plays forwards, including its offset
+    delays_ms: Vec<f32>,
+    last_offset_ms: f32,
+}
+
+impl<T, Time> Default for Timeline<T, Time>
+where
+    T: FloatRepresentable + Clone + Copy + PartialEq,
+    Time: AnimationTime,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Time> Timeline<T, Time>
+where
+    T: FloatRepresentable + Clone + Copy + PartialEq,
+    Time: AnimationTime,
+{
+    /// Creates an empty timeline
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            delays_ms: Vec::new(),
+            last_offset_ms: 0.,
+        }
+    }
+    /// Adds an animation starting at the given offset (a `Duration` or a number of
+    /// milliseconds) into the timeline
+    /// Any delay of the animation itself is applied in addition to the offset
+    pub fn at(mut self, offset: impl IntoMilliseconds, animated: Animated<T, Time>) -> Self {
+        self.push(offset.into_milliseconds(), animated);
+        self
+    }
+    /// Adds an animation starting once every animation added so far has completed
+    pub fn then(mut self, animated: Animated<T, Time>) -> Self {
+        let offset_ms = self.total_duration();
+        self.push(offset_ms, animated);
+        self
+    }
+    /// Adds an animation starting together with the most recently added animation
+    pub fn with(mut self, animated: Animated<T, Time>) -> Self {
+        let offset_ms = self.last_offset_ms;
+        self.push(offset_ms, animated);
+        self
+    }
+    /// Adds animations starting once every animation added so far has completed,
+    /// each one starting `interval` (a `Duration` or a number of milliseconds) after the previous
+    pub fn stagger(
+        mut self,
+        interval: impl IntoMilliseconds,
+        animations: impl IntoIterator<Item = Animated<T, Time>>,
+    ) -> Self {
+        let interval_ms = interval.into_milliseconds();
+        let start_ms = self.total_duration();
+        for (index, animated) in animations.into_iter().enumerate() {
+            self.push(start_ms + index as f32 * interval_ms, animated);
+        }
+        self
+    }
+    fn push(&mut self, offset_ms: f32, mut animated: Animated<T, Time>) {
+        animated.offset_delay(offset_ms);
+        self.last_offset_ms = offset_ms;
+        self.delays_ms.push(animated.delay_ms());
+        self.children.push(animated);
+    }
+    /// The delay of each child's transition to a new value, or `None` for children which
+    /// are already showing it
+    ///
+    /// Transitions towards a lower `float_value` mirror the forward schedule. Interrupting
+    /// the timeline brings the schedule forward, so that the children which have moved
+    /// continue straight away from their current position.
+    fn schedule(&self, new_value: T, at: Time) -> Vec<Option<f32>> {
+        let target = new_value.float_value();
+        let backwards = self
+            .children
+            .first()
+            .is_some_and(|child| target < child.value.float_value());
+        let durations: Vec<f32> = self
+            .children
+            .iter()
+            .map(|child| child.transition_duration(backwards))
+            .collect();
+        // Delays & durations are in milliseconds of playback, which may differ between children
+        let end = self
+            .children
+            .iter()
+            .zip(&self.delays_ms)
+            .zip(&durations)
+            .map(|((child, delay_ms), duration_ms)| (delay_ms + duration_ms) / child.speed().abs())
+            .fold(0., f32::max);
+        let delays: Vec<f32> = self
+            .children
+            .iter()
+            .zip(&self.delays_ms)
+            .zip(&durations)
+            .map(|((child, delay_ms), duration_ms)| {
+                if backwards && end.is_finite() {
+                    end * child.speed().abs() - delay_ms - duration_ms
+                } else {
+                    *delay_ms
+                }
+            })
+            .collect();
+        let moving: Vec<bool> = self
+            .children
+            .iter()
+            .map(|child| child.eased_progress(at) != target)
+            .collect();
+        // Milliseconds of time until the first moving child would begin its transition
+        let skipped = if self.in_progress(at) {
+            self.children
+                .iter()
+                .zip(&delays)
+                .zip(&moving)
+                .filter(|(_, moving)| **moving)
+                .map(|((child, delay_ms), _)| delay_ms / child.speed().abs())
+                .fold(f32::INFINITY, f32::min)
+        } else {
+            0.
+        };
+        let skipped = if skipped.is_finite() { skipped } else { 0. };
+        self.children
+            .iter()
+            .zip(delays)
+            .zip(moving)
+            .map(|((child, delay_ms), moving)| {
+                moving.then(|| f32::max(0., delay_ms - skipped * child.speed().abs()))
+            })
+            .collect()
+    }
+    /// The animations in the timeline, in the order they were added
+    pub fn children(&self) -> &[Animated<T, Time>] {
+        &self.children
+    }
+    /// Transitions every animation in the timeline
+    pub fn transition(&mut self, new_value: T, at: Time) {
+        let schedule = self.schedule(new_value, at);
+        for (child, delay_ms) in self.children.iter_mut().zip(schedule) {
+            match delay_ms {
+                Some(delay_ms) => child.transition_delayed(new_value, at, delay_ms),
+                None => child.transition_instantaneous(new_value, at),
+            }
+        }
+    }
+    /// Transitions every animation in the timeline & instantaneously completes them
+    pub fn transition_instantaneous(&mut self, new_value: T, at: Time) {
+        for child in &mut self.children {
+            child.transition_instantaneous(new_value, at);
+        }
+    }
+    /// Returns whether any animation in the timeline is in progress, given the current time
+    pub fn in_progress(&self, time: Time) -> bool {
+        self.children.iter().any(|child| child.in_progress(time))
+    }
+    /// The duration in milliseconds from the start of the timeline until every animation
+    /// has completed, including offsets & delays
+    /// Timelines containing animations which repeat forever have an infinite duration
+    pub fn total_duration(&self) -> f32 {
+        self.children
+            .iter()
+            .map(|child| child.total_duration())
+            .fold(0., f32::max)
+    }
+}
+
+impl<T, Time> std::ops::Index<usize> for Timeline<T, Time>
+where
+    T: FloatRepresentable + Clone + Copy + PartialEq,
+    Time: AnimationTime,
+{
+    type Output = Animated<T, Time>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.children[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Easing;
+
+    fn child() -> Animated<bool, f32> {
+        Animated::new(false).duration(1000.).easing(Easing::Linear)
+    }
+
+    #[test]
+    fn test_sequence() {
+        let mut timeline = Timeline::new().then(child()).then(child()).then(child());
+        assert_eq!(timeline.total_duration(), 3000.);
+        assert!(!timeline.in_progress(0.));
+
+        timeline.transition(true, 0.);
+        assert_eq!(timeline[0].animate_bool(0., 1., 500.), 0.5);
+        assert_eq!(timeline[1].animate_bool(0., 1., 500.), 0.);
+        assert_eq!(timeline[1].animate_bool(0., 1., 1500.), 0.5);
+        assert_eq!(timeline[2].animate_bool(0., 1., 1500.), 0.);
+        assert_eq!(timeline[2].animate_bool(0., 1., 2500.), 0.5);
+        assert!(timeline.in_progress(2999.));
+        assert!(!timeline.in_progress(3000.));
+    }
+
+    #[test]
+    fn test_parallel() {
+        let mut timeline = Timeline::new()
+            .then(child())
+            .with(child().duration(2000.))
+            .then(child())
+            .at(500., child());
+        assert_eq!(timeline.total_duration(), 3000.);
+
+        timeline.transition(true, 0.);
+        assert_eq!(timeline[0].animate_bool(0., 1., 500.), 0.5);
+        assert_eq!(timeline[1].animate_bool(0., 1., 500.), 0.25);
+        assert_eq!(timeline[2].animate_bool(0., 1., 2500.), 0.5);
+        assert_eq!(timeline[3].animate_bool(0., 1., 1000.), 0.5);
+    }
+
+    #[test]
+    fn test_stagger() {
+        let mut timeline = Timeline::new()
+            .then(child().delay(100.))
+            .stagger(100., (0..3).map(|_| child()));
+        assert_eq!(timeline.children().len(), 4);
+        assert_eq!(timeline.total_duration(), 1100. + 200. + 1000.);
+
+        timeline.transition(true, 0.);
+        assert_eq!(timeline[1].animate_bool(0., 1., 1600.), 0.5);
+        assert_eq!(timeline[2].animate_bool(0., 1., 1700.), 0.5);
+        assert_eq!(timeline[3].animate_bool(0., 1., 1800.), 0.5);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut timeline = Timeline::new()
+            .then(child())
+            .then(child().asymmetric_duration(500.));
+        timeline.transition(true, 0.);
+        assert!(!timeline.in_progress(2000.));
+
+        // The last child to finish is the first to animate back
+        timeline.transition(false, 2000.);
+        assert_eq!(timeline[1].animate_bool(0., 1., 2250.), 0.5);
+        assert_eq!(timeline[0].animate_bool(0., 1., 2250.), 1.);
+        assert_eq!(timeline[0].animate_bool(0., 1., 3000.), 0.5);
+        assert!(timeline.in_progress(3499.));
+        assert!(!timeline.in_progress(3500.));
+
+        // Playing forwards again restores the original schedule
+        timeline.transition(true, 4000.);
+        assert_eq!(timeline[0].animate_bool(0., 1., 4500.), 0.5);
+        assert_eq!(timeline[1].animate_bool(0., 1., 4500.), 0.);
+        assert_eq!(timeline[1].animate_bool(0., 1., 5500.), 0.5);
+    }
+
+    #[test]
+    fn test_reverse_interrupted() {
+        let mut timeline = Timeline::new().then(child()).then(child()).then(child());
+        timeline.transition(true, 0.);
+
+        // Only the running child animates back, continuing from where it was
+        timeline.transition(false, 500.);
+        assert_eq!(timeline[0].animate_bool(0., 1., 500.), 0.5);
+        assert_eq!(timeline[0].animate_bool(0., 1., 1000.), 0.25);
+        assert_eq!(timeline[1].animate_bool(0., 1., 1000.), 0.);
+        assert!(!timeline.in_progress(1500.));
+        assert_eq!(timeline[0].animate_bool(0., 1., 1500.), 0.);
+
+        // Children which have finished follow the running child back, in reverse order
+        timeline.transition(true, 2000.);
+        timeline.transition(false, 3500.);
+        assert_eq!(timeline[1].animate_bool(0., 1., 3500.), 0.5);
+        assert_eq!(timeline[1].animate_bool(0., 1., 4000.), 0.25);
+        assert_eq!(timeline[0].animate_bool(0., 1., 4000.), 1.);
+        assert_eq!(timeline[0].animate_bool(0., 1., 5000.), 0.5);
+        assert_eq!(timeline[2].animate_bool(0., 1., 5000.), 0.);
+        assert!(timeline.in_progress(5499.));
+        assert!(!timeline.in_progress(5500.));
+    }
+
+    #[test]
+    fn test_repeat_forever() {
+        let timeline = Timeline::new().then(child()).then(child().repeat_forever());
+        assert_eq!(timeline.total_duration(), f32::INFINITY);
+    }
+}
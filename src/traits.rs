@@ -1,11 +1,55 @@
+use std::time::Duration;
+
 /// An interface for interacting with time.
 pub trait AnimationTime: Copy + std::fmt::Debug + Send {
+    /// Milliseconds elapsed since the given time, negative if it's in the future
     fn elapsed_since(self, time: Self) -> f32;
+    /// Seconds elapsed since the given time, negative if it's in the future
+    fn seconds_since(self, time: Self) -> f64 {
+        self.elapsed_since(time) as f64 / 1000.
+    }
+    /// Time elapsed since the given time, or zero if it's in the future
+    fn duration_since(self, time: Self) -> Duration {
+        Duration::from_secs_f64(self.seconds_since(time).max(0.))
+    }
 }
 
 impl AnimationTime for std::time::Instant {
     fn elapsed_since(self, time: Self) -> f32 {
-        (self - time).as_millis() as f32
+        (self.seconds_since(time) * 1000.) as f32
+    }
+    fn seconds_since(self, time: Self) -> f64 {
+        if self >= time {
+            (self - time).as_secs_f64()
+        } else {
+            -(time - self).as_secs_f64()
+        }
+    }
+    fn duration_since(self, time: Self) -> Duration {
+        self.saturating_duration_since(time)
+    }
+}
+
+/// A length of time, given either as a `Duration` or as a number of milliseconds
+pub trait IntoMilliseconds {
+    fn into_milliseconds(self) -> f32;
+}
+
+impl IntoMilliseconds for f32 {
+    fn into_milliseconds(self) -> f32 {
+        self
+    }
+}
+
+impl IntoMilliseconds for f64 {
+    fn into_milliseconds(self) -> f32 {
+        self as f32
+    }
+}
+
+impl IntoMilliseconds for Duration {
+    fn into_milliseconds(self) -> f32 {
+        (self.as_secs_f64() * 1000.) as f32
     }
 }
 
@@ -48,6 +92,94 @@ impl Interpolable for f32 {
     }
 }
 
+impl Interpolable for f64 {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        let ratio = ratio as f64;
+        self * (1.0 - ratio) + other * ratio
+    }
+}
+
+/// Integers are interpolated exactly at their endpoints & rounded to the nearest integer
+/// (with halves rounded up) in between, so the result is the same in either direction.
+/// Ratios outside of 0...1 saturate at the bounds of the type.
+macro_rules! impl_interpolable_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Interpolable for $t {
+                fn interpolated(&self, other: Self, ratio: f32) -> Self {
+                    // 64 bit integers lose precision as an `f64`, so the endpoints are exact
+                    if ratio == 0. {
+                        return *self;
+                    } else if ratio == 1. {
+                        return other;
+                    }
+                    let delta = (other as i128 - *self as i128) as f64 * ratio as f64;
+                    let value = *self as i128 + (delta + 0.5).floor() as i128;
+                    value.clamp(<$t>::MIN as i128, <$t>::MAX as i128) as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolable_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_interpolable_tuple {
+    ($($t:ident $index:tt),+) => {
+        impl<$($t),+> Interpolable for ($($t,)+)
+        where
+            $($t: Interpolable),+
+        {
+            fn interpolated(&self, other: Self, ratio: f32) -> Self {
+                ($(self.$index.interpolated(other.$index, ratio),)+)
+            }
+        }
+    };
+}
+
+impl_interpolable_tuple!(A 0);
+impl_interpolable_tuple!(A 0, B 1);
+impl_interpolable_tuple!(A 0, B 1, C 2);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_interpolable_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+impl<T, const N: usize> Interpolable for [T; N]
+where
+    T: Interpolable,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        let mut pairs = self.iter().zip(other);
+        std::array::from_fn(|_| {
+            let (a, b) = pairs.next().expect("arrays have equal lengths");
+            a.interpolated(b, ratio)
+        })
+    }
+}
+
+/// Vectors are interpolated element-wise
+/// Vectors of different lengths can't be interpolated, so the destination is used instead
+impl<T> Interpolable for Vec<T>
+where
+    T: Interpolable,
+{
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        if self.len() != other.len() {
+            return other;
+        }
+        self.iter()
+            .zip(other)
+            .map(|(a, b)| a.interpolated(b, ratio))
+            .collect()
+    }
+}
+
 impl<T> Interpolable for Option<T>
 where
     T: Interpolable + Copy,
@@ -63,6 +195,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "derive")]
+    use lilt_derive::{FloatRepresentable, Interpolable};
 
     #[test]
     fn test_f32_interpolation() {
@@ -110,4 +244,175 @@ mod tests {
         assert_eq!(start.interpolated(end, 0.5), None);
         assert_eq!(start.interpolated(end, 1.0), None);
     }
+
+    #[test]
+    fn test_into_milliseconds() {
+        assert_eq!(250.0f32.into_milliseconds(), 250.);
+        assert_eq!(250.0f64.into_milliseconds(), 250.);
+        assert_eq!(Duration::from_micros(2500).into_milliseconds(), 2.5);
+    }
+
+    #[test]
+    fn test_instant_elapsed() {
+        let start = std::time::Instant::now();
+        let later = start + Duration::from_micros(1500);
+        assert_eq!(later.seconds_since(start), 0.0015);
+        assert_eq!(start.seconds_since(later), -0.0015);
+        assert_eq!(later.elapsed_since(start), 1.5);
+        assert_eq!(later.duration_since(start), Duration::from_micros(1500));
+        assert_eq!(start.duration_since(later), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_f64_interpolation() {
+        let start = 0.0f64;
+        let end = 1e10f64;
+
+        assert_eq!(start.interpolated(end, 0.0), 0.0);
+        assert_eq!(start.interpolated(end, 0.5), 5e9);
+        assert_eq!(start.interpolated(end, 1.0), 1e10);
+    }
+
+    #[test]
+    fn test_integer_interpolation() {
+        assert_eq!(0i32.interpolated(10, 0.24), 2);
+        assert_eq!(0i32.interpolated(10, 0.26), 3);
+        assert_eq!(10i32.interpolated(-10, 0.5), 0);
+        assert_eq!(10u8.interpolated(0, 0.75), 3);
+        assert_eq!(200u8.interpolated(250, 2.0), 255);
+        assert_eq!(10u8.interpolated(0, 2.0), 0);
+        assert_eq!(u64::MAX.interpolated(0, 0.0), u64::MAX);
+        assert_eq!(i64::MIN.interpolated(i64::MAX, 1.0), i64::MAX);
+        assert_eq!(0u64.interpolated(u64::MAX - 1, 1.0), u64::MAX - 1);
+        assert_eq!((u64::MAX - 1).interpolated(0, 0.0), u64::MAX - 1);
+        assert_eq!(0i64.interpolated(i64::MAX - 7, 1.0), i64::MAX - 7);
+        assert_eq!((i64::MIN + 3).interpolated(5, 1.0), 5);
+        assert_eq!(1usize.interpolated(usize::MAX - 2, 1.0), usize::MAX - 2);
+    }
+
+    #[test]
+    fn test_tuple_interpolation() {
+        assert_eq!((0.0f32,).interpolated((10.,), 0.5), (5.,));
+        assert_eq!(
+            (0.0f32, 10.0f64, 0u8).interpolated((10., 0., 100), 0.25),
+            (2.5, 7.5, 25)
+        );
+        assert_eq!(
+            ((0.0f32, 0.0f32), Some(0.0f32)).interpolated(((10., 20.), Some(4.)), 0.5),
+            ((5., 10.), Some(2.))
+        );
+    }
+
+    #[test]
+    fn test_array_interpolation() {
+        let start = [0.0f32, 10., 20., 30.];
+        let end = [10.0f32, 10., 0., 40.];
+
+        assert_eq!(start.interpolated(end, 0.0), start);
+        assert_eq!(start.interpolated(end, 0.5), [5., 10., 10., 35.]);
+        assert_eq!(start.interpolated(end, 1.0), end);
+        assert_eq!([0.0f32; 0].interpolated([], 0.5), [0.0f32; 0]);
+    }
+
+    #[test]
+    fn test_vec_interpolation() {
+        let start = vec![0.0f32, 10.];
+
+        assert_eq!(start.interpolated(vec![10., 0.], 0.5), vec![5., 5.]);
+        assert_eq!(start.interpolated(vec![1., 2., 3.], 0.5), vec![1., 2., 3.]);
+        assert!(start.interpolated(Vec::new(), 0.0).is_empty());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_struct() {
+        #[derive(Interpolable, Debug, PartialEq)]
+        struct Style {
+            opacity: f32,
+            offset: Option<f32>,
+            #[lilt(snap = 0.5)]
+            label: &'static str,
+            #[lilt(skip)]
+            id: u32,
+        }
+
+        #[derive(Interpolable, Debug, PartialEq)]
+        struct Point(f32, #[lilt(snap = 1)] bool);
+
+        let start = Style {
+            opacity: 0.,
+            offset: Some(10.),
+            label: "start",
+            id: 0,
+        };
+        let end = || Style {
+            opacity: 1.,
+            offset: Some(20.),
+            label: "end",
+            id: 1,
+        };
+        assert_eq!(
+            start.interpolated(end(), 0.25),
+            Style {
+                opacity: 0.25,
+                offset: Some(12.5),
+                label: "start",
+                id: 1,
+            }
+        );
+        assert_eq!(start.interpolated(end(), 0.5).label, "end");
+        assert_eq!(
+            Point(0., false).interpolated(Point(10., true), 0.5),
+            Point(5., false)
+        );
+        assert_eq!(
+            Point(0., false).interpolated(Point(10., true), 1.),
+            Point(10., true)
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_enum() {
+        #[derive(Interpolable, Clone, Debug, PartialEq)]
+        #[lilt(snap = 0.25)]
+        enum Shape<T> {
+            Circle { radius: T },
+            Rect(T, T),
+            Hidden,
+        }
+
+        let circle = Shape::Circle { radius: 0. };
+        assert_eq!(
+            circle.interpolated(Shape::Circle { radius: 10. }, 0.5),
+            Shape::Circle { radius: 5. }
+        );
+        assert_eq!(
+            Shape::Rect(0., 10.).interpolated(Shape::Rect(10., 0.), 0.5),
+            Shape::Rect(5., 5.)
+        );
+        assert_eq!(circle.interpolated(Shape::Hidden, 0.2), circle);
+        assert_eq!(circle.interpolated(Shape::Hidden, 0.25), Shape::Hidden);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_float_representable() {
+        #[derive(FloatRepresentable)]
+        enum State {
+            Idle,
+            Loading,
+            Done = 5,
+            #[lilt(value = -1)]
+            Failed,
+            #[lilt(value = 2.5)]
+            Retrying,
+        }
+
+        assert_eq!(State::Idle.float_value(), 0.);
+        assert_eq!(State::Loading.float_value(), 1.);
+        assert_eq!(State::Done.float_value(), 5.);
+        assert_eq!(State::Failed.float_value(), -1.);
+        assert_eq!(State::Retrying.float_value(), 2.5);
+    }
 }
@@ -0,0 +1,453 @@
+use crate::angle::Angle;
+use crate::quaternion::Quaternion;
+use crate::traits::Interpolable;
+use std::f32::consts::PI;
+
+/// A 2D affine transform, interpolated by decomposing it into its translation, rotation,
+/// scale & skew
+///
+/// Interpolating the entries of two matrices distorts shapes midway through a transition -
+/// e.g. a quarter turn shrinks to 70% of its size halfway through. Decomposed transforms
+/// rotate & scale as expected, following the interpolation of CSS transforms.
+///
+/// Points are transformed as row vectors, so `x' = m11 * x + m21 * y + m31` &
+/// `y' = m12 * x + m22 * y + m32`. Rotations are clockwise in screen coordinates, where y
+/// points down.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Angle, Animated, Transform2D};
+/// use std::time::Instant;
+///
+/// let resting = Transform2D::identity();
+/// let lifted = Transform2D::scale(1.2, 1.2)
+///     .then_rotate(Angle::degrees(-5.))
+///     .then_translate(0., -10.);
+///
+/// let now = Instant::now();
+/// let mut hovered: Animated<bool, Instant> = Animated::new(false);
+/// hovered.transition(true, now);
+/// let (x, y) = hovered
+///     .animate_bool(resting, lifted, now)
+///     .transform_point(100., 50.);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2D {
+    pub m11: f32,
+    pub m12: f32,
+    pub m21: f32,
+    pub m22: f32,
+    pub m31: f32,
+    pub m32: f32,
+}
+
+/// The components of a transform, applied as scale, then skew along x, then rotation,
+/// then translation
+#[derive(Clone, Copy, Debug)]
+struct Decomposed2D {
+    translation: (f32, f32),
+    rotation: Angle,
+    scale: (f32, f32),
+    skew: f32,
+}
+
+impl Transform2D {
+    /// Creates a transform from the entries of its matrix
+    pub fn new(m11: f32, m12: f32, m21: f32, m22: f32, m31: f32, m32: f32) -> Self {
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+        }
+    }
+    /// A transform which leaves points unchanged
+    pub fn identity() -> Self {
+        Self::new(1., 0., 0., 1., 0., 0.)
+    }
+    /// A transform which moves points by the given offset
+    pub fn translation(x: f32, y: f32) -> Self {
+        Self::new(1., 0., 0., 1., x, y)
+    }
+    /// A transform which rotates points around the origin
+    pub fn rotation(angle: Angle) -> Self {
+        let (sin, cos) = angle.as_radians().sin_cos();
+        Self::new(cos, sin, -sin, cos, 0., 0.)
+    }
+    /// A transform which scales points from the origin
+    pub fn scale(x: f32, y: f32) -> Self {
+        Self::new(x, 0., 0., y, 0., 0.)
+    }
+    /// A transform which skews points along the x & y axes, like CSS's `skew(x, y)`
+    pub fn skew(x: Angle, y: Angle) -> Self {
+        Self::new(1., y.as_radians().tan(), x.as_radians().tan(), 1., 0., 0.)
+    }
+    /// Applies this transform followed by another
+    pub fn then(&self, other: &Self) -> Self {
+        Self::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.m31 * other.m11 + self.m32 * other.m21 + other.m31,
+            self.m31 * other.m12 + self.m32 * other.m22 + other.m32,
+        )
+    }
+    /// Applies this transform followed by a translation
+    pub fn then_translate(&self, x: f32, y: f32) -> Self {
+        self.then(&Self::translation(x, y))
+    }
+    /// Applies this transform followed by a rotation around the origin
+    pub fn then_rotate(&self, angle: Angle) -> Self {
+        self.then(&Self::rotation(angle))
+    }
+    /// Applies this transform followed by a scale from the origin
+    pub fn then_scale(&self, x: f32, y: f32) -> Self {
+        self.then(&Self::scale(x, y))
+    }
+    /// Transforms a point
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.m11 * x + self.m21 * y + self.m31,
+            self.m12 * x + self.m22 * y + self.m32,
+        )
+    }
+    /// The determinant of the matrix, which is negative when the transform flips points
+    pub fn determinant(&self) -> f32 {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    fn decompose(&self) -> Decomposed2D {
+        let mut scale_x = self.m11.hypot(self.m12);
+        // Flips are represented as a negative scale rather than a half turn, negating the
+        // axis chosen by the CSS decomposition
+        if self.determinant() < 0. && self.m11 < self.m22 {
+            scale_x = -scale_x;
+        }
+        let rotation = if scale_x == 0. {
+            0.
+        } else {
+            (self.m12 / scale_x).atan2(self.m11 / scale_x)
+        };
+        // The y axis with the rotation removed
+        let (sin, cos) = rotation.sin_cos();
+        let sheared = self.m21 * cos + self.m22 * sin;
+        let scale_y = -self.m21 * sin + self.m22 * cos;
+        let skew = if scale_y == 0. {
+            0.
+        } else {
+            (sheared / scale_y).atan()
+        };
+        Decomposed2D {
+            translation: (self.m31, self.m32),
+            rotation: Angle::radians(rotation),
+            scale: (scale_x, scale_y),
+            skew,
+        }
+    }
+
+    fn recompose(decomposed: Decomposed2D) -> Self {
+        let (scale_x, scale_y) = decomposed.scale;
+        let (x, y) = decomposed.translation;
+        Self::new(
+            scale_x,
+            0.,
+            decomposed.skew.tan() * scale_y,
+            scale_y,
+            0.,
+            0.,
+        )
+        .then_rotate(decomposed.rotation)
+        .then_translate(x, y)
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Interpolable for Transform2D {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        let mut from = self.decompose();
+        let to = other.decompose();
+        // A flip along one axis is a half turn away from a flip along the other
+        if (from.scale.0 < 0. && to.scale.1 < 0.) || (from.scale.1 < 0. && to.scale.0 < 0.) {
+            from.scale = (-from.scale.0, -from.scale.1);
+            from.rotation = Angle::radians(from.rotation.as_radians() + PI);
+        }
+        Self::recompose(Decomposed2D {
+            translation: from.translation.interpolated(to.translation, ratio),
+            rotation: from.rotation.interpolated(to.rotation, ratio),
+            scale: from.scale.interpolated(to.scale, ratio),
+            skew: from.skew.interpolated(to.skew, ratio),
+        })
+    }
+}
+
+/// A 3D transform made up of a translation, rotation & scale
+///
+/// Points are scaled, then rotated, then translated. Each component is interpolated
+/// separately, with rotations following the shortest arc between orientations.
+///
+/// # Example
+///
+/// ```rust
+/// use lilt::{Angle, Animated, Quaternion, Transform3D};
+/// use std::time::Instant;
+///
+/// let overview = Transform3D::identity().with_translation((0., 10., 20.));
+/// let closeup = Transform3D::identity()
+///     .with_translation((2., 1., 4.))
+///     .with_rotation(Quaternion::from_axis_angle((0., 1., 0.), Angle::degrees(45.)));
+///
+/// let now = Instant::now();
+/// let mut focused: Animated<bool, Instant> = Animated::new(false);
+/// focused.transition(true, now);
+/// let camera = focused.animate_bool(overview, closeup, now).to_matrix();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform3D {
+    pub translation: (f32, f32, f32),
+    pub rotation: Quaternion,
+    pub scale: (f32, f32, f32),
+}
+
+impl Transform3D {
+    /// Creates a transform from its translation, rotation & scale
+    pub fn new(translation: (f32, f32, f32), rotation: Quaternion, scale: (f32, f32, f32)) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+    /// A transform which leaves points unchanged
+    pub fn identity() -> Self {
+        Self::new((0., 0., 0.), Quaternion::identity(), (1., 1., 1.))
+    }
+    /// Sets the translation of the transform
+    pub fn with_translation(mut self, translation: (f32, f32, f32)) -> Self {
+        self.translation = translation;
+        self
+    }
+    /// Sets the rotation of the transform
+    pub fn with_rotation(mut self, rotation: Quaternion) -> Self {
+        self.rotation = rotation;
+        self
+    }
+    /// Sets the scale of the transform
+    pub fn with_scale(mut self, scale: (f32, f32, f32)) -> Self {
+        self.scale = scale;
+        self
+    }
+    /// Transforms a point
+    pub fn transform_point(&self, point: (f32, f32, f32)) -> (f32, f32, f32) {
+        let scaled = (
+            point.0 * self.scale.0,
+            point.1 * self.scale.1,
+            point.2 * self.scale.2,
+        );
+        let (x, y, z) = self.rotation.rotate_vector(scaled);
+        (
+            x + self.translation.0,
+            y + self.translation.1,
+            z + self.translation.2,
+        )
+    }
+    /// The transform as a column-major 4x4 matrix, as used by most graphics APIs
+    pub fn to_matrix(&self) -> [[f32; 4]; 4] {
+        let Quaternion { x, y, z, w } = self.rotation;
+        let (sx, sy, sz) = self.scale;
+        let (tx, ty, tz) = self.translation;
+        [
+            [
+                (1. - 2. * (y * y + z * z)) * sx,
+                2. * (x * y + w * z) * sx,
+                2. * (x * z - w * y) * sx,
+                0.,
+            ],
+            [
+                2. * (x * y - w * z) * sy,
+                (1. - 2. * (x * x + z * z)) * sy,
+                2. * (y * z + w * x) * sy,
+                0.,
+            ],
+            [
+                2. * (x * z + w * y) * sz,
+                2. * (y * z - w * x) * sz,
+                (1. - 2. * (x * x + y * y)) * sz,
+                0.,
+            ],
+            [tx, ty, tz, 1.],
+        ]
+    }
+}
+
+impl Default for Transform3D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Interpolable for Transform3D {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Self {
+            translation: self.translation.interpolated(other.translation, ratio),
+            rotation: self.rotation.interpolated(other.rotation, ratio),
+            scale: self.scale.interpolated(other.scale, ratio),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Animated, Easing};
+
+    fn approximately_equal(a: Transform2D, b: Transform2D) -> bool {
+        [
+            a.m11 - b.m11,
+            a.m12 - b.m12,
+            a.m21 - b.m21,
+            a.m22 - b.m22,
+            a.m31 - b.m31,
+            a.m32 - b.m32,
+        ]
+        .iter()
+        .all(|difference| difference.abs() < 1e-4)
+    }
+
+    #[test]
+    fn test_composition() {
+        let transform = Transform2D::scale(2., 2.)
+            .then_rotate(Angle::degrees(90.))
+            .then_translate(10., 0.);
+        let (x, y) = transform.transform_point(1., 0.);
+        assert!((x - 10.).abs() < 1e-5 && (y - 2.).abs() < 1e-5);
+        assert!((transform.determinant() - 4.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_decomposition_round_trip() {
+        for transform in [
+            Transform2D::identity(),
+            Transform2D::new(1., 2., 3., 4., 5., 6.),
+            Transform2D::scale(-1., 2.).then_rotate(Angle::degrees(30.)),
+            Transform2D::skew(Angle::degrees(20.), Angle::degrees(-10.))
+                .then_scale(3., 0.5)
+                .then_rotate(Angle::degrees(200.))
+                .then_translate(-4., 8.),
+        ] {
+            let recomposed = Transform2D::recompose(transform.decompose());
+            assert!(approximately_equal(recomposed, transform));
+        }
+    }
+
+    #[test]
+    fn test_decomposed_interpolation() {
+        let from = Transform2D::identity();
+        let to = Transform2D::rotation(Angle::degrees(90.)).then_translate(10., 20.);
+        assert!(approximately_equal(from.interpolated(to, 0.), from));
+        assert!(approximately_equal(from.interpolated(to, 1.), to));
+        // Rotations keep their size rather than interpolating entries
+        assert!(approximately_equal(
+            from.interpolated(to, 0.5),
+            Transform2D::rotation(Angle::degrees(45.)).then_translate(5., 10.)
+        ));
+
+        let from = Transform2D::rotation(Angle::degrees(350.)).then_scale(2., 2.);
+        let to = Transform2D::rotation(Angle::degrees(10.)).then_scale(4., 4.);
+        assert!(approximately_equal(
+            from.interpolated(to, 0.5),
+            Transform2D::scale(3., 3.)
+        ));
+
+        let to = Transform2D::skew(Angle::degrees(40.), Angle::ZERO);
+        assert!(approximately_equal(
+            Transform2D::identity().interpolated(to, 0.5),
+            Transform2D::skew(Angle::degrees(20.), Angle::ZERO)
+        ));
+    }
+
+    #[test]
+    fn test_flip_interpolation() {
+        // Mirroring scales through zero without rotating
+        let mirrored = Transform2D::scale(-1., 1.);
+        let from = Transform2D::identity();
+        assert!(approximately_equal(
+            from.interpolated(mirrored, 0.25),
+            Transform2D::scale(0.5, 1.)
+        ));
+        assert!(approximately_equal(
+            from.interpolated(mirrored, 0.75),
+            Transform2D::scale(-0.5, 1.)
+        ));
+        assert!(approximately_equal(
+            from.interpolated(mirrored, 1.),
+            mirrored
+        ));
+
+        // Flips along different axes rotate between each other & stay flipped
+        let flipped = Transform2D::scale(1., -1.).then_rotate(Angle::degrees(30.));
+        for ratio in [0., 0.25, 0.5, 0.75, 1.] {
+            let determinant = mirrored.interpolated(flipped, ratio).determinant();
+            assert!((determinant + 1.).abs() < 1e-5);
+        }
+        assert!(approximately_equal(
+            mirrored.interpolated(flipped, 1.),
+            flipped
+        ));
+    }
+
+    #[test]
+    fn test_transform_3d() {
+        let transform = Transform3D::identity()
+            .with_scale((2., 2., 2.))
+            .with_rotation(Quaternion::from_axis_angle(
+                (0., 0., 1.),
+                Angle::degrees(90.),
+            ))
+            .with_translation((1., 2., 3.));
+        let (x, y, z) = transform.transform_point((1., 0., 0.));
+        assert!((x - 1.).abs() < 1e-5 && (y - 4.).abs() < 1e-5 && (z - 3.).abs() < 1e-5);
+
+        // The first & last columns transform (1, 0, 0, 1)
+        let matrix = transform.to_matrix();
+        let (x, y, z) = (
+            matrix[0][0] + matrix[3][0],
+            matrix[0][1] + matrix[3][1],
+            matrix[0][2] + matrix[3][2],
+        );
+        assert!((x - 1.).abs() < 1e-5 && (y - 4.).abs() < 1e-5 && (z - 3.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_transform_3d_interruption() {
+        let rotation = |degrees: f32| {
+            Transform3D::identity().with_rotation(Quaternion::from_axis_angle(
+                (0., 1., 0.),
+                Angle::degrees(degrees),
+            ))
+        };
+        let facing = |transform: Transform3D| transform.transform_point((1., 0., 0.));
+        let mut anim = Animated::new(false).duration(1000.).easing(Easing::Linear);
+        anim.transition(true, 0.);
+        let midway = anim.animate_bool(rotation(0.), rotation(90.), 500.);
+        let (x, _, z) = facing(midway);
+        let (expected_x, _, expected_z) = facing(rotation(45.));
+        assert!((x - expected_x).abs() < 1e-5 && (z - expected_z).abs() < 1e-5);
+
+        // Interrupted orientations continue from where they were
+        anim.transition(false, 500.);
+        let interrupted = anim.animate_bool(rotation(0.), rotation(90.), 500.);
+        let (x, _, z) = facing(interrupted);
+        assert!((x - expected_x).abs() < 1e-5 && (z - expected_z).abs() < 1e-5);
+    }
+}